@@ -10,7 +10,13 @@
 //
 // *************************************************************************
 
-use cbb_2d;
+use cbb_2d::{
+    base::FileError,
+    level::{Level, LevelGenerator},
+    recording::MatchRecording,
+    rules::GameResult,
+    universe::Universe,
+};
 use log::*;
 use macroquad::prelude::*;
 use simplelog::*;
@@ -31,9 +37,32 @@ async fn main() {
     if cfg!(debug_assertions) {
         init_log();
     }
+    // --record FILE saves every match, --replay FILE plays a saved match back,
+    // --level FILE plays on a Tiled map instead of a generated level
+    let args: Vec<String> = std::env::args().collect();
+    let arg_value = |name: &str| {
+        args.iter()
+            .position(|arg| arg == name)
+            .and_then(|i| args.get(i + 1).cloned())
+    };
+    let record_path = arg_value("--record");
+    let replay = arg_value("--replay").map(|path| load_or_exit(&path, MatchRecording::load(&path)));
+    let level = arg_value("--level").map(|path| load_or_exit(&path, Level::load_tiled(&path)));
+
     loop {
-        let (p0, p1) = new_game().await;
-        game_over(p0, p1).await;
+        let result = new_game(record_path.as_deref(), replay.clone(), level.as_ref()).await;
+        game_over(result).await;
+    }
+}
+
+/// The game cannot start without the file, print the error and exit
+fn load_or_exit<T>(path: &str, result: Result<T, FileError>) -> T {
+    match result {
+        Ok(value) => value,
+        Err(e) => {
+            eprintln!("Cannot load {}: {}", path, e);
+            std::process::exit(1);
+        }
     }
 }
 
@@ -45,14 +74,26 @@ fn init_log() {
     }));
 }
 
-async fn game_over(p0: u32, p1: u32) {
+async fn game_over(result: GameResult) {
     let mut gamepads = gamepads::Gamepads::new();
 
     'game_over: loop {
         clear_background(BLACK);
         draw_text("Game Over", 100.0, 100.0, 30.0, WHITE);
-        draw_text(&format!("RED: {}", p0), 100.0, 120.0, 30.0, WHITE);
-        draw_text(&format!("BLUE: {}", p1), 100.0, 140.0, 30.0, WHITE);
+        draw_text(
+            format!("RED: {}", result.get_score(0)),
+            100.0,
+            120.0,
+            30.0,
+            WHITE,
+        );
+        draw_text(
+            format!("BLUE: {}", result.get_score(1)),
+            100.0,
+            140.0,
+            30.0,
+            WHITE,
+        );
         draw_text("Press Enter/A to a New Game", 100.0, 200.0, 30.0, WHITE);
 
         gamepads.poll();
@@ -68,38 +109,68 @@ async fn game_over(p0: u32, p1: u32) {
     }
 }
 
-async fn new_game() -> (u32, u32) {
-    let mut universe = cbb_2d::universe::Universe::new();
-    universe.add_player(0, (100.0, 100.0), (30.0, 30.0), 0.5, 0.2, RED);
-    universe.add_player(1, (200.0, 200.0), (20.0, 20.0), 2.0, 0.05, BLUE);
+async fn new_game(
+    record_path: Option<&str>,
+    replay: Option<MatchRecording>,
+    level: Option<&Level>,
+) -> GameResult {
+    let mut universe = Universe::new();
+    if let Some(replay) = replay {
+        universe.start_replay(replay);
+    }
+    let spawn = |i: usize, default| {
+        level
+            .and_then(|level| level.player_spawns.get(i).copied())
+            .unwrap_or(default)
+    };
+    universe.add_player(0, spawn(0, (100.0, 100.0)), (30.0, 30.0), 0.5, 0.2, RED);
+    universe.add_player(1, spawn(1, (200.0, 200.0)), (20.0, 20.0), 2.0, 0.05, BLUE);
+
+    if let Some(level) = level {
+        universe.add_level(level);
+    } else {
+        generate_level(&mut universe);
+    }
+
+    if record_path.is_some() {
+        universe.start_recording();
+    }
+    let result = universe.run().await;
+    if let (Some(path), Some(recording)) = (record_path, universe.stop_recording()) {
+        if let Err(e) = recording.save(path) {
+            log::error!("Cannot save the recording: {e}");
+        }
+    }
+    result
+}
+
+fn generate_level(universe: &mut Universe) {
+    let mut generator = LevelGenerator::new((screen_width(), screen_height()));
+    generator.add_player_spawn((100.0, 100.0));
+    generator.add_player_spawn((200.0, 200.0));
 
     // Add walls around the screen, leaving only on_screen_width
     let thikness = 1000.0;
     let on_screen_width = 10.0;
-    universe.add_wall(
+    generator.add_fixed_wall(
         (0.0 - on_screen_width, -thikness + on_screen_width),
         (screen_width() + 2.0 * on_screen_width, thikness),
     );
-    universe.add_wall(
+    generator.add_fixed_wall(
         (screen_width() - on_screen_width, 0.0 - on_screen_width),
         (thikness, screen_height() + 2.0 * on_screen_width),
     );
-    universe.add_wall(
+    generator.add_fixed_wall(
         (0.0 - on_screen_width, screen_height() - on_screen_width),
         (screen_width() + 2.0 * on_screen_width, thikness),
     );
-    universe.add_wall(
+    generator.add_fixed_wall(
         (-thikness + on_screen_width, 0.0 - on_screen_width),
         (thikness, screen_height() + 2.0 * on_screen_width),
     );
 
-    for _ in 0..40 {
-        universe.add_random_wall();
-    }
-
-    for _ in 0..10 {
-        universe.add_random_collectible();
+    match universe.generate_level(&generator) {
+        Some(level) => universe.add_level(&level),
+        None => log::error!("Cannot generate a level"),
     }
-
-    universe.run().await
 }
@@ -30,6 +30,8 @@ pub struct DynamicAttributes {
 
 impl DynamicAttributes {
     pub const MAX_FORCE: f32 = 100.0;
+    /// Forces and friction are tuned for this number of updates per second
+    pub const REFERENCE_TICK_RATE: f32 = 60.0;
     const F_MULT: f32 = 1.0;
 
     pub fn new(mass: f32, friction_0_1: f32) -> Self {
@@ -76,16 +78,22 @@ impl DynamicAttributes {
     }
 
     pub fn update_position(&mut self, base: &mut GameObjectAttributes, delta_time: f32) {
+        base.store_previous_position();
         let (x, y) = base.get_position();
 
+        // Number of reference ticks in this update, keeps the movement
+        // independent of the update rate
+        let ticks = delta_time * DynamicAttributes::REFERENCE_TICK_RATE;
+
         let acceleration_x = self.fx / self.mass;
         let acceleration_y = self.fy / self.mass;
 
-        self.vel_x += acceleration_x;
-        self.vel_y += acceleration_y;
+        self.vel_x += acceleration_x * ticks;
+        self.vel_y += acceleration_y * ticks;
 
-        self.vel_x *= 1.0 - self.friction;
-        self.vel_y *= 1.0 - self.friction;
+        let damping = (1.0 - self.friction).powf(ticks);
+        self.vel_x *= damping;
+        self.vel_y *= damping;
 
         base.set_position((x + self.vel_x * delta_time, y + self.vel_y * delta_time));
     }
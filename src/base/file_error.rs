@@ -0,0 +1,45 @@
+// *************************************************************************
+//
+// Copyright (c) 2025 Andrei Gramakov. All rights reserved.
+//
+// This file is licensed under the terms of the MIT license.
+// For a copy, see: https://opensource.org/licenses/MIT
+//
+// site:    https://agramakov.me
+// e-mail:  mail@agramakov.me
+//
+// *************************************************************************
+use std::{error, fmt, io};
+
+/// Error reading one of the files of the crate: input maps, recordings and
+/// levels
+#[derive(Debug)]
+pub enum FileError {
+    Io(io::Error),
+    /// Error at a line of the line based formats
+    Parse {
+        line: usize,
+        message: String,
+    },
+    /// The file is broken as a whole or uses unsupported features, e.g. a
+    /// Tiled map
+    Invalid(String),
+}
+
+impl fmt::Display for FileError {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self {
+            FileError::Io(e) => write!(f, "cannot read the file: {}", e),
+            FileError::Parse { line, message } => write!(f, "line {}: {}", line, message),
+            FileError::Invalid(message) => write!(f, "invalid file: {}", message),
+        }
+    }
+}
+
+impl error::Error for FileError {}
+
+impl From<io::Error> for FileError {
+    fn from(e: io::Error) -> Self {
+        FileError::Io(e)
+    }
+}
@@ -9,7 +9,7 @@
 // e-mail:  mail@agramakov.me
 //
 // *************************************************************************
-use macroquad::{color::*, shapes::draw_rectangle};
+use crate::entity::EntityId;
 use rand::Rng;
 use std::{any::Any, ops::Range};
 
@@ -20,50 +20,52 @@ pub trait GameObject: Any {
     fn get_position(&self) -> (f32, f32);
     fn set_position(&mut self, position_xy: (f32, f32));
 
-    fn get_id(&self) -> u32;
-    fn set_id(&mut self, id: u32);
+    /// `EntityId::INVALID` until the object is added to the world
+    fn get_id(&self) -> EntityId;
+    fn set_id(&mut self, id: EntityId);
 
     fn get_name(&self) -> String;
     fn deletion_callback(&self);
 
-    fn draw(&self, scale: f32);
+    /// Draw the object. `alpha` is the fraction of the tick passed since the
+    /// last update, used to interpolate the position.
+    fn draw(&self, scale: f32, alpha: f32);
 }
 
 pub struct GameObjectAttributes {
-    pub id: u32,
+    pub id: EntityId,
     pub x: f32,
     pub y: f32,
     pub width: f32,
     pub height: f32,
+    /// Position at the previous tick, used for the render interpolation
+    pub prev_x: f32,
+    pub prev_y: f32,
     request_deletion: bool,
 }
 
 impl GameObjectAttributes {
-    pub fn new(id: u32, position_xy: (f32, f32), size_wh: (f32, f32)) -> Self {
+    pub fn new(position_xy: (f32, f32), size_wh: (f32, f32)) -> Self {
         Self {
-            id,
+            id: EntityId::INVALID,
             x: position_xy.0,
             y: position_xy.1,
             width: size_wh.0,
             height: size_wh.1,
+            prev_x: position_xy.0,
+            prev_y: position_xy.1,
             request_deletion: false,
         }
     }
 
-    pub fn random(
-        id: u32,
+    pub fn random<R: Rng + ?Sized>(
+        rng: &mut R,
         position_xy: (Range<f32>, Range<f32>),
         size_wh: (Range<f32>, Range<f32>),
     ) -> Self {
-        let mut rng = rand::thread_rng();
-        Self {
-            id,
-            x: rng.gen_range(position_xy.0),
-            y: rng.gen_range(position_xy.1),
-            width: rng.gen_range(size_wh.0),
-            height: rng.gen_range(size_wh.1),
-            request_deletion: false,
-        }
+        let x = rng.gen_range(position_xy.0);
+        let y = rng.gen_range(position_xy.1);
+        Self::new((x, y), (rng.gen_range(size_wh.0), rng.gen_range(size_wh.1)))
     }
 
     pub fn get_position(&self) -> (f32, f32) {
@@ -74,6 +76,20 @@ impl GameObjectAttributes {
         self.y = position_xy.1;
     }
 
+    /// Remember the current position as the previous one, call before moving
+    pub fn store_previous_position(&mut self) {
+        self.prev_x = self.x;
+        self.prev_y = self.y;
+    }
+
+    /// Position between the previous and the current one, `alpha` in [0.0, 1.0]
+    pub fn get_interpolated_position(&self, alpha: f32) -> (f32, f32) {
+        (
+            self.prev_x + (self.x - self.prev_x) * alpha,
+            self.prev_y + (self.y - self.prev_y) * alpha,
+        )
+    }
+
     pub fn request_deletion(&mut self) {
         self.request_deletion = true;
     }
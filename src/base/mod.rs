@@ -11,9 +11,9 @@
 // *************************************************************************
 mod game_object;
 mod dynamic;
-mod any;
+mod file_error;
 pub use game_object::GameObjectAttributes;
 pub use game_object::GameObject;
 pub use dynamic::Dynamic;
 pub use dynamic::DynamicAttributes;
-pub use any::AsAny;
+pub use file_error::FileError;
@@ -0,0 +1,22 @@
+// *************************************************************************
+//
+// Copyright (c) 2025 Andrei Gramakov. All rights reserved.
+//
+// This file is licensed under the terms of the MIT license.
+// For a copy, see: https://opensource.org/licenses/MIT
+//
+// site:    https://agramakov.me
+// e-mail:  mail@agramakov.me
+//
+// *************************************************************************
+use crate::{base::GameObject, sys_collision::Collidable, sys_event::Subscriber};
+
+/// Object kept in the `EntityStore`. Its attribute structs are the components
+/// the systems work on: the position and the size, the dynamics, the collider
+/// and the own state, e.g. the score of a player.
+pub trait Entity: GameObject + Collidable + Send {
+    /// Objects reacting to the events on the bus return themselves
+    fn as_subscriber_mut(&mut self) -> Option<&mut dyn Subscriber> {
+        None
+    }
+}
@@ -0,0 +1,184 @@
+// *************************************************************************
+//
+// Copyright (c) 2025 Andrei Gramakov. All rights reserved.
+//
+// This file is licensed under the terms of the MIT license.
+// For a copy, see: https://opensource.org/licenses/MIT
+//
+// site:    https://agramakov.me
+// e-mail:  mail@agramakov.me
+//
+// *************************************************************************
+use super::{Entity, EntityId};
+use crate::sys_collision::BodyType;
+use std::any::Any;
+
+struct Slot {
+    generation: u32,
+    entity: Option<Box<dyn Entity>>,
+}
+
+/// Owns every object of the world once. The systems look the objects up by
+/// their ids and iterate over them in the order of the slots.
+///
+/// There are no separate arrays per component type: every entity keeps its
+/// own components (see `Entity`) and the systems reach them through the
+/// entity traits, or through the concrete type with `iter_as`. Splitting
+/// the objects into per-component storage is not part of the store.
+#[derive(Default)]
+pub struct EntityStore {
+    slots: Vec<Slot>,
+    /// Slots of the removed entities, reused first
+    free_indices: Vec<u32>,
+    len: usize,
+    /// Changes whenever a static body is added or removed
+    static_revision: u64,
+}
+
+impl EntityStore {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Gives the entity its id
+    pub fn insert(&mut self, mut entity: Box<dyn Entity>) -> EntityId {
+        if entity.get_body_type() == BodyType::Static {
+            self.static_revision += 1;
+        }
+        self.len += 1;
+        let index = match self.free_indices.pop() {
+            Some(index) => index,
+            None => {
+                self.slots.push(Slot {
+                    generation: 0,
+                    entity: None,
+                });
+                self.slots.len() as u32 - 1
+            }
+        };
+        let slot = &mut self.slots[index as usize];
+        let id = EntityId::new(index, slot.generation);
+        entity.set_id(id);
+        slot.entity = Some(entity);
+        id
+    }
+
+    /// `None` if the entity is already removed
+    pub fn remove(&mut self, id: EntityId) -> Option<Box<dyn Entity>> {
+        let slot = self.slots.get_mut(id.get_index() as usize)?;
+        if slot.generation != id.get_generation() {
+            return None;
+        }
+        let entity = slot.entity.take()?;
+        slot.generation += 1;
+        self.free_indices.push(id.get_index());
+        self.len -= 1;
+        if entity.get_body_type() == BodyType::Static {
+            self.static_revision += 1;
+        }
+        Some(entity)
+    }
+
+    pub fn contains(&self, id: EntityId) -> bool {
+        self.get(id).is_some()
+    }
+
+    pub fn get(&self, id: EntityId) -> Option<&dyn Entity> {
+        let slot = self.slots.get(id.get_index() as usize)?;
+        if slot.generation != id.get_generation() {
+            return None;
+        }
+        slot.entity.as_deref()
+    }
+
+    pub fn get_mut(&mut self, id: EntityId) -> Option<&mut (dyn Entity + 'static)> {
+        let slot = self.slots.get_mut(id.get_index() as usize)?;
+        if slot.generation != id.get_generation() {
+            return None;
+        }
+        slot.entity.as_deref_mut()
+    }
+
+    /// Both entities at once, e.g. to resolve their collision. `None` if
+    /// one of them is missing or the ids are the same.
+    pub fn get_pair_mut(
+        &mut self,
+        id: EntityId,
+        other_id: EntityId,
+    ) -> Option<(&mut (dyn Entity + 'static), &mut (dyn Entity + 'static))> {
+        let [slot, other_slot] = self
+            .slots
+            .get_disjoint_mut([id.get_index() as usize, other_id.get_index() as usize])
+            .ok()?;
+        if slot.generation != id.get_generation()
+            || other_slot.generation != other_id.get_generation()
+        {
+            return None;
+        }
+        Some((
+            slot.entity.as_deref_mut()?,
+            other_slot.entity.as_deref_mut()?,
+        ))
+    }
+
+    /// The entity as its concrete type, `None` if it is of another type
+    pub fn get_as<T: Entity>(&self, id: EntityId) -> Option<&T> {
+        let entity: &dyn Any = self.get(id)?;
+        entity.downcast_ref()
+    }
+
+    pub fn get_as_mut<T: Entity>(&mut self, id: EntityId) -> Option<&mut T> {
+        let entity: &mut dyn Any = self.get_mut(id)?;
+        entity.downcast_mut()
+    }
+
+    pub fn len(&self) -> usize {
+        self.len
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.len == 0
+    }
+
+    pub fn get_static_revision(&self) -> u64 {
+        self.static_revision
+    }
+
+    /// Call after moving or resizing a static body, so the systems caching
+    /// the static bodies rebuild their data
+    pub fn invalidate_static(&mut self) {
+        self.static_revision += 1;
+    }
+
+    pub fn iter(&self) -> impl Iterator<Item = (EntityId, &dyn Entity)> {
+        self.slots.iter().enumerate().filter_map(|(index, slot)| {
+            let id = EntityId::new(index as u32, slot.generation);
+            slot.entity.as_deref().map(|entity| (id, entity))
+        })
+    }
+
+    pub fn iter_mut(&mut self) -> impl Iterator<Item = (EntityId, &mut (dyn Entity + 'static))> {
+        self.slots
+            .iter_mut()
+            .enumerate()
+            .filter_map(|(index, slot)| {
+                let id = EntityId::new(index as u32, slot.generation);
+                slot.entity.as_deref_mut().map(|entity| (id, entity))
+            })
+    }
+
+    /// Entities of the concrete type, e.g. all players
+    pub fn iter_as<T: Entity>(&self) -> impl Iterator<Item = (EntityId, &T)> {
+        self.iter().filter_map(|(id, entity)| {
+            let entity: &dyn Any = entity;
+            entity.downcast_ref().map(|entity| (id, entity))
+        })
+    }
+
+    pub fn iter_as_mut<T: Entity>(&mut self) -> impl Iterator<Item = (EntityId, &mut T)> {
+        self.iter_mut().filter_map(|(id, entity)| {
+            let entity: &mut dyn Any = entity;
+            entity.downcast_mut().map(|entity| (id, entity))
+        })
+    }
+}
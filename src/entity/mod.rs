@@ -0,0 +1,19 @@
+// *************************************************************************
+//
+// Copyright (c) 2025 Andrei Gramakov. All rights reserved.
+//
+// This file is licensed under the terms of the MIT license.
+// For a copy, see: https://opensource.org/licenses/MIT
+//
+// site:    https://agramakov.me
+// e-mail:  mail@agramakov.me
+//
+// *************************************************************************
+#[allow(clippy::module_inception)]
+mod entity;
+mod entity_id;
+mod entity_store;
+
+pub use entity::Entity;
+pub use entity_id::EntityId;
+pub use entity_store::EntityStore;
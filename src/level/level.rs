@@ -0,0 +1,34 @@
+// *************************************************************************
+//
+// Copyright (c) 2025 Andrei Gramakov. All rights reserved.
+//
+// This file is licensed under the terms of the MIT license.
+// For a copy, see: https://opensource.org/licenses/MIT
+//
+// site:    https://agramakov.me
+// e-mail:  mail@agramakov.me
+//
+// *************************************************************************
+
+/// Description of a level, added to the universe with `Universe::add_level`
+#[derive(Debug, Clone, Default)]
+pub struct Level {
+    pub size_wh: (f32, f32),
+    /// Position and size of every wall
+    pub walls: Vec<((f32, f32), (f32, f32))>,
+    /// Where the players start, in the order of the player ids
+    pub player_spawns: Vec<(f32, f32)>,
+    /// Collectibles present from the start
+    pub collectibles: Vec<(f32, f32)>,
+    /// Where new collectibles may appear. Anywhere on the screen if empty.
+    pub collectible_spawn_points: Vec<(f32, f32)>,
+}
+
+impl Level {
+    pub fn new(size_wh: (f32, f32)) -> Self {
+        Self {
+            size_wh,
+            ..Default::default()
+        }
+    }
+}
@@ -0,0 +1,272 @@
+// *************************************************************************
+//
+// Copyright (c) 2025 Andrei Gramakov. All rights reserved.
+//
+// This file is licensed under the terms of the MIT license.
+// For a copy, see: https://opensource.org/licenses/MIT
+//
+// site:    https://agramakov.me
+// e-mail:  mail@agramakov.me
+//
+// *************************************************************************
+use super::Level;
+use crate::objects::COLLECTIBLE_SIZE;
+use rand::Rng;
+use std::{collections::VecDeque, ops::Range};
+
+/// Generates random levels where walls keep a distance from each other and
+/// from the player spawns, and every collectible can be reached from every
+/// spawn. Layouts failing the check are thrown away and generated again.
+pub struct LevelGenerator {
+    size_wh: (f32, f32),
+    fixed_walls: Vec<((f32, f32), (f32, f32))>,
+    wall_count: usize,
+    wall_size_wh: (Range<f32>, Range<f32>),
+    min_spacing: f32,
+    spawn_clearance: f32,
+    player_spawns: Vec<(f32, f32)>,
+    player_size_wh: (f32, f32),
+    collectible_count: usize,
+    cell_size: f32,
+    max_attempts: u32,
+}
+
+impl LevelGenerator {
+    /// How many times a single wall is placed before giving up on it
+    const WALL_PLACEMENT_TRIES: u32 = 50;
+
+    pub fn new(size_wh: (f32, f32)) -> Self {
+        Self {
+            size_wh,
+            fixed_walls: Vec::new(),
+            wall_count: 40,
+            wall_size_wh: (30.0..100.0, 30.0..100.0),
+            min_spacing: 40.0,
+            spawn_clearance: 20.0,
+            player_spawns: Vec::new(),
+            player_size_wh: (30.0, 30.0),
+            collectible_count: 10,
+            cell_size: 10.0,
+            max_attempts: 20,
+        }
+    }
+
+    /// Wall that is always part of the level, e.g. a border. It is taken
+    /// into account when the random walls are placed.
+    pub fn add_fixed_wall(&mut self, position_xy: (f32, f32), size_wh: (f32, f32)) {
+        self.fixed_walls.push((position_xy, size_wh));
+    }
+
+    /// Number and size of the random walls. Sizes bigger than the level are
+    /// cut to the level size, an empty range makes `generate` return `None`.
+    pub fn set_walls(&mut self, count: usize, size_wh: (Range<f32>, Range<f32>)) {
+        self.wall_count = count;
+        self.wall_size_wh = size_wh;
+    }
+
+    /// Minimal gap between two walls
+    pub fn set_min_spacing(&mut self, min_spacing: f32) {
+        self.min_spacing = min_spacing;
+    }
+
+    /// Free space kept around every player spawn
+    pub fn set_spawn_clearance(&mut self, spawn_clearance: f32) {
+        self.spawn_clearance = spawn_clearance;
+    }
+
+    pub fn add_player_spawn(&mut self, position_xy: (f32, f32)) {
+        self.player_spawns.push(position_xy);
+    }
+
+    /// Size of the biggest player, used to check where the players fit
+    pub fn set_player_size(&mut self, size_wh: (f32, f32)) {
+        self.player_size_wh = size_wh;
+    }
+
+    pub fn set_collectible_count(&mut self, count: usize) {
+        self.collectible_count = count;
+    }
+
+    /// Resolution of the reachability search, should not be bigger than
+    /// the thinnest wall. Panics if it is not a positive number.
+    pub fn set_cell_size(&mut self, cell_size: f32) {
+        assert!(
+            cell_size > 0.0 && cell_size.is_finite(),
+            "invalid cell size {}",
+            cell_size
+        );
+        self.cell_size = cell_size;
+    }
+
+    pub fn set_max_attempts(&mut self, max_attempts: u32) {
+        self.max_attempts = max_attempts;
+    }
+
+    /// `None` if no valid layout was found in `max_attempts` or the wall
+    /// sizes cannot fit in the level
+    pub fn generate<R: Rng + ?Sized>(&self, rng: &mut R) -> Option<Level> {
+        if self.wall_count > 0 && self.get_wall_size_range().is_none() {
+            log::warn!(
+                "Wall size {:?} does not fit in a level of {:?}",
+                self.wall_size_wh,
+                self.size_wh
+            );
+            return None;
+        }
+        for attempt in 0..self.max_attempts {
+            let walls = self.place_walls(rng);
+            if let Some(level) = self.check_and_populate(rng, walls) {
+                return Some(level);
+            }
+            log::debug!("Level attempt {} is not fully reachable", attempt);
+        }
+        None
+    }
+
+    /// Wall size ranges cut to the level size, `None` if no wall fits
+    fn get_wall_size_range(&self) -> Option<(Range<f32>, Range<f32>)> {
+        let fit = |range: &Range<f32>, length: f32| {
+            let fitted = range.start..range.end.min(length);
+            (range.start > 0.0 && !fitted.is_empty()).then_some(fitted)
+        };
+        Some((
+            fit(&self.wall_size_wh.0, self.size_wh.0)?,
+            fit(&self.wall_size_wh.1, self.size_wh.1)?,
+        ))
+    }
+
+    fn place_walls<R: Rng + ?Sized>(&self, rng: &mut R) -> Vec<((f32, f32), (f32, f32))> {
+        let (width, height) = self.size_wh;
+        let mut walls = self.fixed_walls.clone();
+        let Some((wall_w, wall_h)) = self.get_wall_size_range() else {
+            return walls;
+        };
+        for _ in 0..self.wall_count {
+            for _ in 0..LevelGenerator::WALL_PLACEMENT_TRIES {
+                let size = (rng.gen_range(wall_w.clone()), rng.gen_range(wall_h.clone()));
+                let position = (
+                    rng.gen_range(0.0..width - size.0),
+                    rng.gen_range(0.0..height - size.1),
+                );
+
+                let too_close_to_wall = walls
+                    .iter()
+                    .any(|wall| overlaps((position, size), *wall, self.min_spacing));
+                let blocks_spawn = self.player_spawns.iter().any(|spawn| {
+                    overlaps(
+                        (position, size),
+                        (*spawn, self.player_size_wh),
+                        self.spawn_clearance,
+                    )
+                });
+                if !too_close_to_wall && !blocks_spawn {
+                    walls.push((position, size));
+                    break;
+                }
+            }
+        }
+        walls
+    }
+
+    /// Flood fill the positions a player can take. The level is valid when
+    /// all spawns are in one area, collectibles are placed in that area.
+    fn check_and_populate<R: Rng + ?Sized>(
+        &self,
+        rng: &mut R,
+        walls: Vec<((f32, f32), (f32, f32))>,
+    ) -> Option<Level> {
+        let (pw, ph) = self.player_size_wh;
+        let cols = ((self.size_wh.0 - pw) / self.cell_size).floor() as i32 + 1;
+        let rows = ((self.size_wh.1 - ph) / self.cell_size).floor() as i32 + 1;
+        if cols <= 0 || rows <= 0 {
+            return None;
+        }
+        let cell_position =
+            |col: i32, row: i32| (col as f32 * self.cell_size, row as f32 * self.cell_size);
+        let free: Vec<bool> = (0..rows)
+            .flat_map(|row| (0..cols).map(move |col| (col, row)))
+            .map(|(col, row)| {
+                let player = (cell_position(col, row), self.player_size_wh);
+                !walls.iter().any(|wall| overlaps(player, *wall, 0.0))
+            })
+            .collect();
+        let index = |col: i32, row: i32| (row * cols + col) as usize;
+
+        let spawn_cells: Vec<(i32, i32)> = self
+            .player_spawns
+            .iter()
+            .map(|(x, y)| {
+                (
+                    ((x / self.cell_size).round() as i32).clamp(0, cols - 1),
+                    ((y / self.cell_size).round() as i32).clamp(0, rows - 1),
+                )
+            })
+            .collect();
+        let start = spawn_cells.first().copied().unwrap_or((cols / 2, rows / 2));
+        if !free[index(start.0, start.1)] {
+            return None;
+        }
+
+        let mut reachable = vec![false; free.len()];
+        let mut queue = VecDeque::from([start]);
+        reachable[index(start.0, start.1)] = true;
+        while let Some((col, row)) = queue.pop_front() {
+            for (dc, dr) in [(1, 0), (-1, 0), (0, 1), (0, -1)] {
+                let (c, r) = (col + dc, row + dr);
+                if c < 0 || r < 0 || c >= cols || r >= rows {
+                    continue;
+                }
+                let i = index(c, r);
+                if free[i] && !reachable[i] {
+                    reachable[i] = true;
+                    queue.push_back((c, r));
+                }
+            }
+        }
+        if !spawn_cells.iter().all(|(c, r)| reachable[index(*c, *r)]) {
+            return None;
+        }
+
+        // A collectible in the middle of a reachable player position can
+        // always be picked up
+        let offset = ((pw - COLLECTIBLE_SIZE) / 2.0, (ph - COLLECTIBLE_SIZE) / 2.0);
+        let spawn_points: Vec<(f32, f32)> = (0..rows)
+            .flat_map(|row| (0..cols).map(move |col| (col, row)))
+            .filter(|(col, row)| reachable[index(*col, *row)])
+            .map(|(col, row)| {
+                let (x, y) = cell_position(col, row);
+                (x + offset.0, y + offset.1)
+            })
+            .filter(|point| {
+                !self.player_spawns.iter().any(|spawn| {
+                    overlaps(
+                        (*point, (COLLECTIBLE_SIZE, COLLECTIBLE_SIZE)),
+                        (*spawn, self.player_size_wh),
+                        0.0,
+                    )
+                })
+            })
+            .collect();
+        if spawn_points.is_empty() {
+            return None;
+        }
+
+        let collectibles = (0..self.collectible_count)
+            .map(|_| spawn_points[rng.gen_range(0..spawn_points.len())])
+            .collect();
+
+        Some(Level {
+            size_wh: self.size_wh,
+            walls,
+            player_spawns: self.player_spawns.clone(),
+            collectibles,
+            collectible_spawn_points: spawn_points,
+        })
+    }
+}
+
+/// Whether two rectangles, given as position and size, are closer than `gap`
+fn overlaps(a: ((f32, f32), (f32, f32)), b: ((f32, f32), (f32, f32)), gap: f32) -> bool {
+    let (((ax, ay), (aw, ah)), ((bx, by), (bw, bh))) = (a, b);
+    ax < bx + bw + gap && ax + aw + gap > bx && ay < by + bh + gap && ay + ah + gap > by
+}
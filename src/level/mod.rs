@@ -0,0 +1,22 @@
+// *************************************************************************
+//
+// Copyright (c) 2025 Andrei Gramakov. All rights reserved.
+//
+// This file is licensed under the terms of the MIT license.
+// For a copy, see: https://opensource.org/licenses/MIT
+//
+// site:    https://agramakov.me
+// e-mail:  mail@agramakov.me
+//
+// *************************************************************************
+#[allow(clippy::module_inception)]
+mod level;
+mod level_generator;
+mod tiled_background;
+mod tiled_level;
+mod tiled_map;
+
+pub use level::Level;
+pub use level_generator::LevelGenerator;
+pub use tiled_background::TiledBackground;
+pub use tiled_level::TILED_COLLISION_LAYER;
@@ -0,0 +1,80 @@
+// *************************************************************************
+//
+// Copyright (c) 2025 Andrei Gramakov. All rights reserved.
+//
+// This file is licensed under the terms of the MIT license.
+// For a copy, see: https://opensource.org/licenses/MIT
+//
+// site:    https://agramakov.me
+// e-mail:  mail@agramakov.me
+//
+// *************************************************************************
+use super::{
+    tiled_map::{TiledLayer, TiledMap},
+    TILED_COLLISION_LAYER,
+};
+use crate::base::FileError;
+use macroquad::{math::Rect, texture::Texture2D};
+use macroquad_tiled::Map;
+use nanoserde::{DeJson, SerJson};
+
+/// Draws the tile layers of a Tiled map behind the objects
+pub struct TiledBackground {
+    map: Map,
+    /// Tile layers in the drawing order
+    layers: Vec<String>,
+    size_wh: (f32, f32),
+}
+
+impl TiledBackground {
+    /// `textures` are the tileset images by the names used in the map
+    pub fn new(json: &str, textures: &[(&str, Texture2D)]) -> Result<Self, FileError> {
+        let mut tiled_map =
+            TiledMap::deserialize_json(json).map_err(|e| FileError::Invalid(e.to_string()))?;
+        if tiled_map
+            .tilesets
+            .iter()
+            .any(|tileset| !tileset.source.is_empty())
+        {
+            return Err(FileError::Invalid(
+                "external tilesets are not supported".into(),
+            ));
+        }
+
+        // macroquad-tiled 0.2 knows neither object groups nor group layers,
+        // it gets only the tile layers to draw
+        let mut tile_layers = Vec::new();
+        collect_tile_layers(std::mem::take(&mut tiled_map.layers), &mut tile_layers);
+        let layers = tile_layers.iter().map(|layer| layer.name.clone()).collect();
+        tiled_map.layers = tile_layers;
+        let map = macroquad_tiled::load_map(&tiled_map.serialize_json(), textures, &[])
+            .map_err(|e| FileError::Invalid(e.to_string()))?;
+
+        Ok(Self {
+            map,
+            layers,
+            size_wh: (
+                (tiled_map.width * tiled_map.tilewidth) as f32,
+                (tiled_map.height * tiled_map.tileheight) as f32,
+            ),
+        })
+    }
+
+    pub fn draw(&self, scale: f32) {
+        let dest = Rect::new(0.0, 0.0, self.size_wh.0 * scale, self.size_wh.1 * scale);
+        for layer in &self.layers {
+            self.map.draw_tiles(layer, dest, None);
+        }
+    }
+}
+
+/// Tile layers in the drawing order, the ones inside groups included
+fn collect_tile_layers(layers: Vec<TiledLayer>, tile_layers: &mut Vec<TiledLayer>) {
+    for layer in layers {
+        match layer.ty.as_str() {
+            "tilelayer" if layer.name != TILED_COLLISION_LAYER => tile_layers.push(layer),
+            "group" => collect_tile_layers(layer.layers, tile_layers),
+            _ => {}
+        }
+    }
+}
@@ -0,0 +1,138 @@
+// *************************************************************************
+//
+// Copyright (c) 2025 Andrei Gramakov. All rights reserved.
+//
+// This file is licensed under the terms of the MIT license.
+// For a copy, see: https://opensource.org/licenses/MIT
+//
+// site:    https://agramakov.me
+// e-mail:  mail@agramakov.me
+//
+// *************************************************************************
+use super::{
+    tiled_map::{TiledLayer, TiledMap},
+    Level,
+};
+use crate::{base::FileError, objects::COLLECTIBLE_SIZE};
+use nanoserde::DeJson;
+use std::{fs, path::Path};
+
+/// Tile layer whose tiles become walls
+pub const TILED_COLLISION_LAYER: &str = "collision";
+
+/// Object types, set in the `Class` (or `Type`) field in Tiled
+const WALL: &str = "wall";
+/// The object name is the player id, spawns without an id come last
+const PLAYER_SPAWN: &str = "player_spawn";
+const COLLECTIBLE: &str = "collectible";
+/// A point or an area where new collectibles may appear
+const COLLECTIBLE_SPAWNER: &str = "collectible_spawner";
+
+/// Flip and rotation flags stored in the high bits of a tile id
+const TILE_FLAGS_MASK: u32 = 0xF000_0000;
+
+impl Level {
+    /// Build a level from a Tiled JSON map. The tiles of the `collision`
+    /// layer and the objects of the `wall` type become walls, objects of
+    /// the `player_spawn`, `collectible` and `collectible_spawner` types
+    /// mark the player spawns and the collectibles.
+    pub fn from_tiled_json(json: &str) -> Result<Level, FileError> {
+        let map =
+            TiledMap::deserialize_json(json).map_err(|e| FileError::Invalid(e.to_string()))?;
+        if map.infinite {
+            return Err(FileError::Invalid("infinite maps are not supported".into()));
+        }
+
+        let tile_size = (map.tilewidth as f32, map.tileheight as f32);
+        let mut level = Level::new((
+            map.width as f32 * tile_size.0,
+            map.height as f32 * tile_size.1,
+        ));
+        let mut spawns: Vec<(Option<u32>, (f32, f32))> = Vec::new();
+        for layer in &map.layers {
+            add_layer(&mut level, &mut spawns, layer, tile_size);
+        }
+
+        // Stable sort keeps the map order of the spawns without an id
+        spawns.sort_by_key(|(id, _)| id.unwrap_or(u32::MAX));
+        level.player_spawns = spawns.into_iter().map(|(_, position)| position).collect();
+        Ok(level)
+    }
+
+    pub fn load_tiled<P: AsRef<Path>>(path: P) -> Result<Level, FileError> {
+        Level::from_tiled_json(&fs::read_to_string(path)?)
+    }
+}
+
+fn add_layer(
+    level: &mut Level,
+    spawns: &mut Vec<(Option<u32>, (f32, f32))>,
+    layer: &TiledLayer,
+    tile_size: (f32, f32),
+) {
+    match layer.ty.as_str() {
+        "tilelayer" if layer.name == TILED_COLLISION_LAYER => {
+            add_collision_tiles(level, layer, tile_size)
+        }
+        "objectgroup" => {
+            for object in &layer.objects {
+                let position = (object.x, object.y);
+                let size = (object.width, object.height);
+                match object.get_type() {
+                    WALL => level.walls.push((position, size)),
+                    PLAYER_SPAWN => spawns.push((object.name.parse().ok(), position)),
+                    COLLECTIBLE => level.collectibles.push(position),
+                    COLLECTIBLE_SPAWNER => add_spawn_area(level, position, size),
+                    _ => {}
+                }
+            }
+        }
+        "group" => {
+            for sublayer in &layer.layers {
+                add_layer(level, spawns, sublayer, tile_size);
+            }
+        }
+        _ => {}
+    }
+}
+
+/// Consecutive tiles of a row are merged into one wall
+fn add_collision_tiles(level: &mut Level, layer: &TiledLayer, tile_size: (f32, f32)) {
+    let (tw, th) = tile_size;
+    for row in 0..layer.height as usize {
+        let mut run_start = None;
+        for col in 0..=layer.width as usize {
+            let solid = col < layer.width as usize
+                && layer
+                    .data
+                    .get(row * layer.width as usize + col)
+                    .is_some_and(|gid| gid & !TILE_FLAGS_MASK != 0);
+            match (solid, run_start) {
+                (true, None) => run_start = Some(col),
+                (false, Some(start)) => {
+                    level.walls.push((
+                        (start as f32 * tw, row as f32 * th),
+                        ((col - start) as f32 * tw, th),
+                    ));
+                    run_start = None;
+                }
+                _ => {}
+            }
+        }
+    }
+}
+
+/// Points spread over the area so that the collectibles stay inside it
+fn add_spawn_area(level: &mut Level, position: (f32, f32), size: (f32, f32)) {
+    let step = 2.0 * COLLECTIBLE_SIZE;
+    let cols = ((size.0 - COLLECTIBLE_SIZE) / step).floor().max(0.0) as u32 + 1;
+    let rows = ((size.1 - COLLECTIBLE_SIZE) / step).floor().max(0.0) as u32 + 1;
+    for row in 0..rows {
+        for col in 0..cols {
+            level.collectible_spawn_points.push((
+                position.0 + col as f32 * step,
+                position.1 + row as f32 * step,
+            ));
+        }
+    }
+}
@@ -0,0 +1,91 @@
+// *************************************************************************
+//
+// Copyright (c) 2025 Andrei Gramakov. All rights reserved.
+//
+// This file is licensed under the terms of the MIT license.
+// For a copy, see: https://opensource.org/licenses/MIT
+//
+// site:    https://agramakov.me
+// e-mail:  mail@agramakov.me
+//
+// *************************************************************************
+//! Part of the Tiled JSON map format needed to build a level,
+//! see https://doc.mapeditor.org/en/stable/reference/json-map-format/
+//!
+//! `macroquad_tiled::Map` cannot be used for levels: it is only built with
+//! all tileset textures loaded, and it does not know object groups, group
+//! layers and the object `class`. The background passes this model, cut
+//! to the drawn layers, to `macroquad_tiled::load_map`. nanoserde is the
+//! JSON library macroquad-tiled is built on.
+use nanoserde::{DeJson, SerJson};
+
+#[derive(Debug, Default, DeJson, SerJson)]
+#[nserde(default)]
+pub struct TiledMap {
+    pub width: u32,
+    pub height: u32,
+    pub tilewidth: u32,
+    pub tileheight: u32,
+    pub infinite: bool,
+    pub layers: Vec<TiledLayer>,
+    pub tilesets: Vec<TiledTileset>,
+}
+
+#[derive(Debug, Default, DeJson, SerJson)]
+#[nserde(default)]
+pub struct TiledLayer {
+    pub name: String,
+    /// `tilelayer`, `objectgroup`, `imagelayer` or `group`
+    #[nserde(rename = "type")]
+    pub ty: String,
+    pub width: u32,
+    pub height: u32,
+    #[nserde(default = "1.0")]
+    pub opacity: f32,
+    /// Global tile ids, row by row, 0 is an empty cell
+    pub data: Vec<u32>,
+    pub objects: Vec<TiledObject>,
+    /// Sublayers of a group layer
+    pub layers: Vec<TiledLayer>,
+}
+
+#[derive(Debug, Default, DeJson, SerJson)]
+#[nserde(default)]
+pub struct TiledObject {
+    pub name: String,
+    /// Object type up to Tiled 1.8
+    #[nserde(rename = "type")]
+    pub ty: String,
+    /// Object type since Tiled 1.9
+    pub class: String,
+    pub x: f32,
+    pub y: f32,
+    pub width: f32,
+    pub height: f32,
+}
+
+/// Tileset embedded in the map, `source` is set for external tilesets
+#[derive(Debug, Default, DeJson, SerJson)]
+#[nserde(default)]
+pub struct TiledTileset {
+    pub name: String,
+    pub firstgid: u32,
+    pub tilecount: u32,
+    pub columns: u32,
+    pub tilewidth: u32,
+    pub tileheight: u32,
+    pub margin: u32,
+    pub spacing: u32,
+    pub image: String,
+    pub source: String,
+}
+
+impl TiledObject {
+    pub fn get_type(&self) -> &str {
+        if self.class.is_empty() {
+            &self.ty
+        } else {
+            &self.class
+        }
+    }
+}
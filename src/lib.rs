@@ -11,10 +11,15 @@
 // *************************************************************************
 
 pub mod base;
+pub mod entity;
+pub mod level;
 pub mod objects;
+pub mod recording;
+pub mod rules;
 pub mod sys_collision;
 pub mod sys_event;
 pub mod sys_input;
 pub mod sys_osd;
+pub mod timestep;
 pub mod universe;
 pub mod world;
@@ -12,46 +12,72 @@
 
 use std::ops::Range;
 
-use super::CollidableType;
+use super::CollisionLayer;
 use crate::{
     base::{GameObject, GameObjectAttributes},
-    sys_collision::Collidable,
+    entity::{Entity, EntityId},
+    sys_collision::{
+        BodyType, Collidable, CollidableAttributes, CollidableType, CollisionFilter, CollisionShape,
+    },
 };
-use macroquad::{color::GREEN, shapes::draw_rectangle};
+use macroquad::color::GREEN;
+use rand::Rng;
 
 pub struct Collectable {
     obj_attr: GameObjectAttributes,
+    col_attr: CollidableAttributes,
 }
 
-static COLLECTIBLE_SIZE: f32 = 10.0;
+pub static COLLECTIBLE_SIZE: f32 = 10.0;
 
 impl Collectable {
     pub fn new(x: f32, y: f32) -> Collectable {
+        let size_wh = (COLLECTIBLE_SIZE, COLLECTIBLE_SIZE);
         Collectable {
-            obj_attr: GameObjectAttributes::new(0, (x, y), (COLLECTIBLE_SIZE, COLLECTIBLE_SIZE)),
+            obj_attr: GameObjectAttributes::new((x, y), size_wh),
+            col_attr: Self::new_collidable_attributes(CollisionShape::Rectangle, size_wh),
         }
     }
 
-    pub fn random(position_xy: (Range<f32>, Range<f32>)) -> Collectable {
-        Collectable {
-            obj_attr: GameObjectAttributes::random(
-                0,
-                (position_xy.0, position_xy.1),
-                (
-                    COLLECTIBLE_SIZE..COLLECTIBLE_SIZE + 1.0,
-                    COLLECTIBLE_SIZE..COLLECTIBLE_SIZE + 1.0,
-                ),
+    pub fn random<R: Rng + ?Sized>(
+        rng: &mut R,
+        position_xy: (Range<f32>, Range<f32>),
+    ) -> Collectable {
+        let obj_attr = GameObjectAttributes::random(
+            rng,
+            (position_xy.0, position_xy.1),
+            (
+                COLLECTIBLE_SIZE..COLLECTIBLE_SIZE + 1.0,
+                COLLECTIBLE_SIZE..COLLECTIBLE_SIZE + 1.0,
             ),
+        );
+        let size_wh = (obj_attr.width, obj_attr.height);
+        Collectable {
+            obj_attr,
+            col_attr: Self::new_collidable_attributes(CollisionShape::Rectangle, size_wh),
         }
     }
+
+    fn new_collidable_attributes(
+        shape: CollisionShape,
+        size_wh: (f32, f32),
+    ) -> CollidableAttributes {
+        let mut col_attr = CollidableAttributes::new(BodyType::Kinematic, shape, size_wh);
+        col_attr.set_sensor(true);
+        col_attr.set_filter(CollisionFilter::new(
+            CollisionLayer::COLLECTIBLE,
+            CollisionLayer::PLAYER,
+        ));
+        col_attr
+    }
 }
 
 impl GameObject for Collectable {
-    fn get_id(&self) -> u32 {
+    fn get_id(&self) -> EntityId {
         self.obj_attr.id
     }
 
-    fn set_id(&mut self, id: u32) {
+    fn set_id(&mut self, id: EntityId) {
         self.obj_attr.id = id;
     }
 
@@ -76,21 +102,22 @@ impl GameObject for Collectable {
         format!("Collectable loc[{},{}]", self.obj_attr.x, self.obj_attr.y)
     }
 
-    fn draw(&self, _scale: f32) {
-        draw_rectangle(
-            self.obj_attr.x * _scale,
-            self.obj_attr.y * _scale,
-            self.obj_attr.width * _scale,
-            self.obj_attr.height * _scale,
-            GREEN,
+    fn draw(&self, _scale: f32, alpha: f32) {
+        let (x, y) = self.obj_attr.get_interpolated_position(alpha);
+        let center_xy = (
+            x + self.obj_attr.width / 2.0,
+            y + self.obj_attr.height / 2.0,
         );
+        self.col_attr.draw(center_xy, _scale, GREEN);
     }
-    
+
     fn deletion_callback(&self) {
         // Do nothing
     }
 }
 
+impl Entity for Collectable {}
+
 impl Collidable for Collectable {
     fn get_x(&self) -> f32 {
         self.obj_attr.x
@@ -108,14 +135,6 @@ impl Collidable for Collectable {
         self.obj_attr.height
     }
 
-    fn borrow_mut_dynamic_attributes(&mut self) -> &mut crate::base::DynamicAttributes {
-        unimplemented!()
-    }
-
-    fn borrow_dynamic_attributes(&self) -> &crate::base::DynamicAttributes {
-        unimplemented!()
-    }
-
     fn borrow_mut_base_object(&mut self) -> &mut crate::base::GameObjectAttributes {
         &mut self.obj_attr
     }
@@ -124,14 +143,25 @@ impl Collidable for Collectable {
         &self.obj_attr
     }
 
-    fn get_collidable_type(&self) -> u32 {
-        CollidableType::Collectable.to_int()
+    fn borrow_collidable_attributes(&self) -> &CollidableAttributes {
+        &self.col_attr
     }
 
-    fn process_collision(&mut self, other: &dyn Collidable) {
-        // Do nothing
-        if other.get_collidable_type() == CollidableType::Player.to_int() {
-            self.borrow_mut_base_object().request_deletion();
+    fn borrow_mut_collidable_attributes(&mut self) -> &mut CollidableAttributes {
+        &mut self.col_attr
+    }
+
+    fn get_collidable_type(&self) -> CollidableType {
+        CollidableType::COLLECTABLE
+    }
+
+    fn process_collision(&mut self, _other: &dyn Collidable) {
+        // Do nothing, a sensor
+    }
+
+    fn on_sensor_enter(&mut self, other: &dyn Collidable) {
+        if other.get_collidable_type() == CollidableType::PLAYER {
+            self.obj_attr.request_deletion();
         }
     }
 }
@@ -0,0 +1,23 @@
+// *************************************************************************
+//
+// Copyright (c) 2025 Andrei Gramakov. All rights reserved.
+//
+// This file is licensed under the terms of the MIT license.
+// For a copy, see: https://opensource.org/licenses/MIT
+//
+// site:    https://agramakov.me
+// e-mail:  mail@agramakov.me
+//
+// *************************************************************************
+
+/// Collision layers of the built-in objects, for `CollisionFilter`
+pub struct CollisionLayer;
+
+impl CollisionLayer {
+    pub const WALL: u32 = 1 << 0;
+    pub const PLAYER: u32 = 1 << 1;
+    pub const COLLECTIBLE: u32 = 1 << 2;
+    pub const ZONE: u32 = 1 << 3;
+    /// Layers from this one up are free for the game, e.g. for teams
+    pub const FIRST_CUSTOM: u32 = 1 << 8;
+}
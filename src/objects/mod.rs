@@ -11,13 +11,15 @@
 // *************************************************************************
 
 mod collectible;
-mod collidable_type;
+mod collision_layer;
 mod player;
 mod player_mode;
 mod wall;
+mod zone;
 
-pub use collectible::Collectable;
-pub use collidable_type::CollidableType;
+pub use collectible::{Collectable, COLLECTIBLE_SIZE};
+pub use collision_layer::CollisionLayer;
 pub use player::Player;
 pub use player_mode::PlayerMode;
 pub use wall::Wall;
+pub use zone::Zone;
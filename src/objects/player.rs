@@ -9,14 +9,17 @@
 // e-mail:  mail@agramakov.me
 //
 // *************************************************************************
-use super::{CollidableType, PlayerMode};
+use super::{CollisionLayer, PlayerMode};
 use crate::{
     base::{Dynamic, DynamicAttributes, GameObject, GameObjectAttributes},
-    sys_collision::Collidable,
+    entity::{Entity, EntityId},
+    sys_collision::{
+        BodyType, Collidable, CollidableAttributes, CollidableType, CollisionFilter, CollisionShape,
+    },
     sys_event::{Event, Subscriber},
 };
 use macroquad::prelude::*;
-use std::{clone, fmt};
+use std::fmt;
 
 pub struct Player {
     pub player_id: u32,
@@ -24,6 +27,9 @@ pub struct Player {
     dynamic_data: DynamicAttributes,
     dynamic_data_default: DynamicAttributes,
     obj_attr: GameObjectAttributes,
+    col_attr: CollidableAttributes,
+    /// Filter in the normal mode, the no clip mode ignores walls
+    collision_filter_default: CollisionFilter,
     pub score: u32,
     pub color_default: Color,
     pub color_current: Color,
@@ -32,25 +38,25 @@ pub struct Player {
 impl Player {
     pub fn new(
         player_id: u32,
-        obj_id: u32,
         position_xy: (f32, f32),
         size_wh: (f32, f32),
         mass: f32,
         friction: f32,
         color: Color,
     ) -> Self {
-        // Create a new player object and subscribe to the event bus
-        let player = Self {
+        let col_attr = Self::new_collidable_attributes(CollisionShape::Rectangle, size_wh);
+        Self {
             player_id,
             player_mode: PlayerMode::Normal,
             dynamic_data: DynamicAttributes::new(mass, friction),
             dynamic_data_default: DynamicAttributes::new(mass, friction),
-            obj_attr: GameObjectAttributes::new(obj_id, position_xy, size_wh),
+            obj_attr: GameObjectAttributes::new(position_xy, size_wh),
+            collision_filter_default: col_attr.get_filter(),
+            col_attr,
             score: 0,
             color_default: color,
             color_current: color,
-        };
-        player
+        }
     }
 
     pub fn set_mode(&mut self, mode: PlayerMode) {
@@ -67,15 +73,45 @@ impl Player {
             }
         }
         self.player_mode = mode;
+        self.update_collision_filter();
+    }
+
+    /// The no clip mode ignores walls
+    fn update_collision_filter(&mut self) {
+        let mut filter = self.collision_filter_default;
+        if let PlayerMode::NoClip = self.player_mode {
+            filter.mask &= !CollisionLayer::WALL;
+        }
+        self.col_attr.set_filter(filter);
+    }
+
+    /// Shape centred in the player's bounding box, e.g. a ball for round players
+    pub fn set_shape(&mut self, shape: CollisionShape) {
+        self.col_attr
+            .set_shape(shape, (self.obj_attr.width, self.obj_attr.height));
+    }
+
+    fn new_collidable_attributes(
+        shape: CollisionShape,
+        size_wh: (f32, f32),
+    ) -> CollidableAttributes {
+        let mut col_attr = CollidableAttributes::new(BodyType::Dynamic, shape, size_wh);
+        col_attr.set_filter(CollisionFilter::new(
+            CollisionLayer::PLAYER,
+            CollisionFilter::ALL,
+        ));
+        // Light players are fast enough to skip thin walls
+        col_attr.set_ccd_enabled(true);
+        col_attr
     }
 }
 
 impl GameObject for Player {
-    fn get_id(&self) -> u32 {
+    fn get_id(&self) -> EntityId {
         self.obj_attr.id
     }
 
-    fn set_id(&mut self, id: u32) {
+    fn set_id(&mut self, id: EntityId) {
         self.obj_attr.id = id;
     }
 
@@ -100,14 +136,13 @@ impl GameObject for Player {
         format!("Player no {}", self.player_id)
     }
 
-    fn draw(&self, _scale: f32) {
-        draw_rectangle(
-            self.obj_attr.x * _scale,
-            self.obj_attr.y * _scale,
-            self.obj_attr.width * _scale,
-            self.obj_attr.height * _scale,
-            self.color_current,
+    fn draw(&self, _scale: f32, alpha: f32) {
+        let (x, y) = self.obj_attr.get_interpolated_position(alpha);
+        let center_xy = (
+            x + self.obj_attr.width / 2.0,
+            y + self.obj_attr.height / 2.0,
         );
+        self.col_attr.draw(center_xy, _scale, self.color_current);
     }
 
     fn deletion_callback(&self) {
@@ -148,12 +183,12 @@ impl Collidable for Player {
         self.obj_attr.height
     }
 
-    fn borrow_mut_dynamic_attributes(&mut self) -> &mut DynamicAttributes {
-        &mut self.dynamic_data
+    fn borrow_mut_dynamic_attributes(&mut self) -> Option<&mut DynamicAttributes> {
+        Some(&mut self.dynamic_data)
     }
 
-    fn borrow_dynamic_attributes(&self) -> &DynamicAttributes {
-        &self.dynamic_data
+    fn borrow_dynamic_attributes(&self) -> Option<&DynamicAttributes> {
+        Some(&self.dynamic_data)
     }
 
     fn borrow_base_object(&self) -> &GameObjectAttributes {
@@ -164,50 +199,56 @@ impl Collidable for Player {
         &mut self.obj_attr
     }
 
-    fn process_collision(&mut self, other: &dyn Collidable) {
-        let other_type = CollidableType::from_int(other.get_collidable_type());
+    fn borrow_collidable_attributes(&self) -> &CollidableAttributes {
+        &self.col_attr
+    }
 
-        match other_type {
-            CollidableType::SolidObstacle => match self.player_mode {
-                PlayerMode::Normal => {
-                    self.compensate_overlap(other);
-                    self.change_dynamics(other);
-                }
-                PlayerMode::NoClip => {}
-            },
-            CollidableType::Collectable => {
-                self.score += 1;
-                log::debug!("Player {} score: {}", self.player_id, self.score);
-            }
-            _ => {}
-        }
+    fn borrow_mut_collidable_attributes(&mut self) -> &mut CollidableAttributes {
+        &mut self.col_attr
+    }
+
+    fn process_collision(&mut self, _other: &dyn Collidable) {
+        // Do nothing, SysCollision resolves the contact and scoring happens
+        // on the sensor events
+    }
+
+    /// The filter of the normal mode, the no clip mode derives from it
+    fn set_collision_filter(&mut self, filter: CollisionFilter) {
+        self.collision_filter_default = filter;
+        self.update_collision_filter();
     }
 
-    fn get_collidable_type(&self) -> u32 {
-        CollidableType::Player.to_int()
+    fn get_collidable_type(&self) -> CollidableType {
+        CollidableType::PLAYER
+    }
+}
+
+impl Entity for Player {
+    fn as_subscriber_mut(&mut self) -> Option<&mut dyn Subscriber> {
+        Some(self)
     }
 }
 
 impl Subscriber for Player {
     fn handle_busevent(&mut self, event: &Event) {
         match event {
-            Event::MovePlayer { id, fx, fy } => {
-                if *id == self.player_id {
-                    self.apply_force((*fx, *fy));
-                }
+            Event::MovePlayer { id, fx, fy } if *id == self.player_id => {
+                self.apply_force((*fx, *fy));
             }
-            Event::ChangeColor { id, color } => {
-                if *id == self.player_id {
-                    match color {
-                        None => self.color_current = self.color_default,
-                        Some(color) => self.color_current = *color,
-                    }
-                }
+            Event::ChangeColor { id, color } if *id == self.player_id => match color {
+                None => self.color_current = self.color_default,
+                Some(color) => self.color_current = *color,
+            },
+            Event::SetPlayerMode { id, mode } if *id == self.player_id => {
+                self.set_mode(mode.clone());
             }
-            Event::SetPlayerMode { id, mode } => {
-                if *id == self.player_id {
-                    self.set_mode(mode.clone());
-                }
+            Event::SensorEntered {
+                sensor_type,
+                object_id,
+                ..
+            } if *object_id == self.obj_attr.id && *sensor_type == CollidableType::COLLECTABLE => {
+                self.score += 1;
+                log::debug!("Player {} score: {}", self.player_id, self.score);
             }
             _ => {}
         }
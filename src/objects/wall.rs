@@ -10,38 +10,64 @@
 //
 // *************************************************************************
 
-use macroquad::{
-    color::{GRAY, WHITE},
-    shapes::draw_rectangle,
-};
+use macroquad::color::GRAY;
 
-use super::CollidableType;
+use super::CollisionLayer;
 use crate::{
     base::{GameObject, GameObjectAttributes},
-    sys_collision::Collidable,
+    entity::{Entity, EntityId},
+    sys_collision::{
+        BodyType, Collidable, CollidableAttributes, CollidableType, CollisionFilter, CollisionShape,
+    },
 };
+use rand::Rng;
 use std::ops::Range;
 
 pub struct Wall {
     obj_attr: GameObjectAttributes,
+    col_attr: CollidableAttributes,
 }
 
 impl Wall {
-    pub fn new(id: u32, position_xy: (f32, f32), size_wh: (f32, f32)) -> Self {
+    pub fn new(position_xy: (f32, f32), size_wh: (f32, f32)) -> Self {
+        Self {
+            obj_attr: GameObjectAttributes::new(position_xy, size_wh),
+            col_attr: Self::new_collidable_attributes(CollisionShape::Rectangle, size_wh),
+        }
+    }
+
+    /// Wall of any shape, centred in the bounding box
+    pub fn new_shaped(position_xy: (f32, f32), size_wh: (f32, f32), shape: CollisionShape) -> Self {
         Self {
-            obj_attr: GameObjectAttributes::new(id, position_xy, size_wh),
+            obj_attr: GameObjectAttributes::new(position_xy, size_wh),
+            col_attr: Self::new_collidable_attributes(shape, size_wh),
         }
     }
 
-    pub fn random(
-        id: u32,
+    pub fn random<R: Rng + ?Sized>(
+        rng: &mut R,
         position_xy: (Range<f32>, Range<f32>),
         size_wh: (Range<f32>, Range<f32>),
     ) -> Self {
+        let obj_attr = GameObjectAttributes::random(rng, position_xy, size_wh);
+        let size_wh = (obj_attr.width, obj_attr.height);
         Self {
-            obj_attr: GameObjectAttributes::random(id, position_xy, size_wh),
+            obj_attr,
+            col_attr: Self::new_collidable_attributes(CollisionShape::Rectangle, size_wh),
         }
     }
+
+    fn new_collidable_attributes(
+        shape: CollisionShape,
+        size_wh: (f32, f32),
+    ) -> CollidableAttributes {
+        let mut col_attr = CollidableAttributes::new(BodyType::Static, shape, size_wh);
+        col_attr.set_filter(CollisionFilter::new(
+            CollisionLayer::WALL,
+            CollisionFilter::ALL,
+        ));
+        col_attr
+    }
 }
 
 impl GameObject for Wall {
@@ -62,19 +88,21 @@ impl GameObject for Wall {
         self.obj_attr.y = position_xy.1;
     }
 
-    fn get_id(&self) -> u32 {
+    fn get_id(&self) -> EntityId {
         self.obj_attr.id
     }
 
-    fn set_id(&mut self, id: u32) {
+    fn set_id(&mut self, id: EntityId) {
         self.obj_attr.id = id;
     }
 
-    fn draw(&self, _scale: f32) {
-        let color = GRAY;
-        let (x, y) = self.get_position();
-        let (w, h) = (self.obj_attr.width, self.obj_attr.height);
-        draw_rectangle(x * _scale, y * _scale, w * _scale, h * _scale, color);
+    fn draw(&self, _scale: f32, alpha: f32) {
+        let (x, y) = self.obj_attr.get_interpolated_position(alpha);
+        let center_xy = (
+            x + self.obj_attr.width / 2.0,
+            y + self.obj_attr.height / 2.0,
+        );
+        self.col_attr.draw(center_xy, _scale, GRAY);
     }
 
     fn get_name(&self) -> String {
@@ -86,6 +114,8 @@ impl GameObject for Wall {
     }
 }
 
+impl Entity for Wall {}
+
 impl Collidable for Wall {
     fn get_x(&self) -> f32 {
         self.obj_attr.x
@@ -103,14 +133,6 @@ impl Collidable for Wall {
         self.obj_attr.height
     }
 
-    fn borrow_mut_dynamic_attributes(&mut self) -> &mut crate::base::DynamicAttributes {
-        unimplemented!()
-    }
-
-    fn borrow_dynamic_attributes(&self) -> &crate::base::DynamicAttributes {
-        unimplemented!()
-    }
-
     fn borrow_mut_base_object(&mut self) -> &mut crate::base::GameObjectAttributes {
         &mut self.obj_attr
     }
@@ -119,8 +141,16 @@ impl Collidable for Wall {
         &self.obj_attr
     }
 
-    fn get_collidable_type(&self) -> u32 {
-        CollidableType::SolidObstacle.to_int()
+    fn borrow_collidable_attributes(&self) -> &CollidableAttributes {
+        &self.col_attr
+    }
+
+    fn borrow_mut_collidable_attributes(&mut self) -> &mut CollidableAttributes {
+        &mut self.col_attr
+    }
+
+    fn get_collidable_type(&self) -> CollidableType {
+        CollidableType::SOLID_OBSTACLE
     }
 
     fn process_collision(&mut self, _other: &dyn Collidable) {
@@ -0,0 +1,138 @@
+// *************************************************************************
+//
+// Copyright (c) 2025 Andrei Gramakov. All rights reserved.
+//
+// This file is licensed under the terms of the MIT license.
+// For a copy, see: https://opensource.org/licenses/MIT
+//
+// site:    https://agramakov.me
+// e-mail:  mail@agramakov.me
+//
+// *************************************************************************
+
+use super::CollisionLayer;
+use crate::{
+    base::{GameObject, GameObjectAttributes},
+    entity::{Entity, EntityId},
+    sys_collision::{
+        BodyType, Collidable, CollidableAttributes, CollidableType, CollisionFilter, CollisionShape,
+    },
+};
+use macroquad::color::Color;
+
+/// Area reporting the objects inside without stopping them, e.g. a goal, a
+/// slow zone or a teleporter. The game reacts to the sensor events.
+pub struct Zone {
+    obj_attr: GameObjectAttributes,
+    col_attr: CollidableAttributes,
+    color: Color,
+}
+
+impl Zone {
+    pub fn new(
+        position_xy: (f32, f32),
+        size_wh: (f32, f32),
+        shape: CollisionShape,
+        color: Color,
+    ) -> Self {
+        let mut col_attr = CollidableAttributes::new(BodyType::Static, shape, size_wh);
+        col_attr.set_sensor(true);
+        col_attr.set_filter(CollisionFilter::new(
+            CollisionLayer::ZONE,
+            CollisionFilter::ALL,
+        ));
+        Self {
+            obj_attr: GameObjectAttributes::new(position_xy, size_wh),
+            col_attr,
+            color,
+        }
+    }
+}
+
+impl GameObject for Zone {
+    fn get_mut_object_attr(&mut self) -> &mut GameObjectAttributes {
+        &mut self.obj_attr
+    }
+
+    fn get_object_attr(&self) -> &GameObjectAttributes {
+        &self.obj_attr
+    }
+
+    fn get_position(&self) -> (f32, f32) {
+        (self.obj_attr.x, self.obj_attr.y)
+    }
+
+    fn set_position(&mut self, position_xy: (f32, f32)) {
+        self.obj_attr.x = position_xy.0;
+        self.obj_attr.y = position_xy.1;
+    }
+
+    fn get_id(&self) -> EntityId {
+        self.obj_attr.id
+    }
+
+    fn set_id(&mut self, id: EntityId) {
+        self.obj_attr.id = id;
+    }
+
+    fn draw(&self, scale: f32, alpha: f32) {
+        let (x, y) = self.obj_attr.get_interpolated_position(alpha);
+        let center_xy = (
+            x + self.obj_attr.width / 2.0,
+            y + self.obj_attr.height / 2.0,
+        );
+        self.col_attr.draw(center_xy, scale, self.color);
+    }
+
+    fn get_name(&self) -> String {
+        format!("Zone loc[{},{}]", self.obj_attr.x, self.obj_attr.y)
+    }
+
+    fn deletion_callback(&self) {
+        // Do nothing
+    }
+}
+
+impl Entity for Zone {}
+
+impl Collidable for Zone {
+    fn get_x(&self) -> f32 {
+        self.obj_attr.x
+    }
+
+    fn get_y(&self) -> f32 {
+        self.obj_attr.y
+    }
+
+    fn get_width(&self) -> f32 {
+        self.obj_attr.width
+    }
+
+    fn get_height(&self) -> f32 {
+        self.obj_attr.height
+    }
+
+    fn borrow_mut_base_object(&mut self) -> &mut GameObjectAttributes {
+        &mut self.obj_attr
+    }
+
+    fn borrow_base_object(&self) -> &GameObjectAttributes {
+        &self.obj_attr
+    }
+
+    fn borrow_collidable_attributes(&self) -> &CollidableAttributes {
+        &self.col_attr
+    }
+
+    fn borrow_mut_collidable_attributes(&mut self) -> &mut CollidableAttributes {
+        &mut self.col_attr
+    }
+
+    fn get_collidable_type(&self) -> CollidableType {
+        CollidableType::ZONE
+    }
+
+    fn process_collision(&mut self, _other: &dyn Collidable) {
+        // Do nothing, a sensor
+    }
+}
@@ -0,0 +1,116 @@
+// *************************************************************************
+//
+// Copyright (c) 2025 Andrei Gramakov. All rights reserved.
+//
+// This file is licensed under the terms of the MIT license.
+// For a copy, see: https://opensource.org/licenses/MIT
+//
+// site:    https://agramakov.me
+// e-mail:  mail@agramakov.me
+//
+// *************************************************************************
+use crate::{base::FileError, sys_input::InputState};
+use std::{fs, io, path::Path};
+
+/// Everything needed to play a match back: the random seed the level was
+/// built with and the input polled before every tick. The events are not
+/// stored, replaying the input publishes them again.
+///
+/// The file is line based:
+/// ```text
+/// seed 1234
+/// tick_rate 60
+/// input 0 key:Right key:D
+/// ```
+#[derive(Debug, Clone)]
+pub struct MatchRecording {
+    pub seed: u64,
+    pub tick_rate: f32,
+    /// Input frames with the number of the tick they were read before, in
+    /// order, see `ReplayInput`
+    pub inputs: Vec<(u64, InputState)>,
+}
+
+impl MatchRecording {
+    pub fn new(seed: u64, tick_rate: f32) -> Self {
+        Self {
+            seed,
+            tick_rate,
+            inputs: Vec::new(),
+        }
+    }
+
+    pub fn push_input(&mut self, tick: u64, frame: InputState) {
+        self.inputs.push((tick, frame));
+    }
+
+    /// Input frames read before the tick, there may be several or none
+    pub fn get_tick_inputs(&self, tick: u64) -> impl Iterator<Item = &InputState> {
+        let start = self.inputs.partition_point(|(t, _)| *t < tick);
+        self.inputs[start..]
+            .iter()
+            .take_while(move |(t, _)| *t == tick)
+            .map(|(_, frame)| frame)
+    }
+
+    /// Tick of the last recorded input frame
+    pub fn get_last_tick(&self) -> u64 {
+        self.inputs.last().map(|(tick, _)| *tick).unwrap_or(0)
+    }
+
+    pub fn to_record_string(&self) -> String {
+        let mut text = format!("seed {}\ntick_rate {}\n", self.seed, self.tick_rate);
+        for (tick, frame) in &self.inputs {
+            text += format!("input {} {}", tick, frame.to_record_line()).trim_end();
+            text += "\n";
+        }
+        text
+    }
+
+    pub fn parse(text: &str) -> Result<Self, FileError> {
+        let mut recording = MatchRecording::new(0, 0.0);
+        for (index, line) in text.lines().enumerate() {
+            let error = |message: String| FileError::Parse {
+                line: index + 1,
+                message,
+            };
+            let words: Vec<&str> = line.split_whitespace().collect();
+            match words.as_slice() {
+                [] => continue,
+                ["seed", seed] => {
+                    recording.seed = seed
+                        .parse()
+                        .map_err(|_| error(format!("invalid seed '{}'", seed)))?;
+                }
+                ["tick_rate", rate] => {
+                    recording.tick_rate = rate
+                        .parse()
+                        .map_err(|_| error(format!("invalid tick rate '{}'", rate)))?;
+                }
+                ["input", tick, frame @ ..] => {
+                    let tick: u64 = tick
+                        .parse()
+                        .map_err(|_| error(format!("invalid tick '{}'", tick)))?;
+                    if tick < recording.get_last_tick() {
+                        return Err(error("ticks are not in order".to_string()));
+                    }
+                    let frame = InputState::parse_record_line(&frame.join(" ")).map_err(error)?;
+                    recording.push_input(tick, frame);
+                }
+                _ => return Err(error(format!("unknown entry '{}'", line))),
+            }
+        }
+        if recording.tick_rate <= 0.0 {
+            return Err(FileError::Invalid("no tick rate".to_string()));
+        }
+        Ok(recording)
+    }
+
+    pub fn load<P: AsRef<Path>>(path: P) -> Result<Self, FileError> {
+        MatchRecording::parse(&fs::read_to_string(path)?)
+    }
+
+    pub fn save<P: AsRef<Path>>(&self, path: P) -> io::Result<()> {
+        fs::write(path, self.to_record_string())
+    }
+}
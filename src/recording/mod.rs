@@ -8,16 +8,7 @@
 // site:    https://agramakov.me
 // e-mail:  mail@agramakov.me
 //
+// *************************************************************************
+mod match_recording;
 
-use std::any::Any;
-
-/// For downcasting
-pub trait AsAny {
-    fn as_any(&self) -> &dyn Any;
-}
-
-impl<T: Any> AsAny for T {
-    fn as_any(&self) -> &dyn Any {
-        self
-    }
-}
+pub use match_recording::MatchRecording;
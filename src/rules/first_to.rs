@@ -0,0 +1,53 @@
+// *************************************************************************
+//
+// Copyright (c) 2025 Andrei Gramakov. All rights reserved.
+//
+// This file is licensed under the terms of the MIT license.
+// For a copy, see: https://opensource.org/licenses/MIT
+//
+// site:    https://agramakov.me
+// e-mail:  mail@agramakov.me
+//
+// *************************************************************************
+use super::{GameResult, GameRules, RespawnOnScore};
+use crate::universe::Universe;
+
+/// The first player reaching the target score wins
+pub struct FirstTo {
+    target_score: u32,
+    respawn: RespawnOnScore,
+}
+
+impl FirstTo {
+    pub fn new(target_score: u32) -> Self {
+        Self {
+            target_score,
+            respawn: RespawnOnScore::new(),
+        }
+    }
+}
+
+impl GameRules for FirstTo {
+    fn update(&mut self, universe: &mut Universe, _delta_time: f32) {
+        self.respawn.update(universe);
+    }
+
+    fn is_game_over(&self, universe: &Universe) -> bool {
+        universe
+            .get_player_ids()
+            .into_iter()
+            .any(|id| universe.get_player_score(id) >= self.target_score)
+    }
+
+    fn get_status_text(&self, universe: &Universe) -> String {
+        format!(
+            "FIRST TO {}, {}",
+            self.target_score,
+            GameResult::from_scores(universe).get_scores_text()
+        )
+    }
+
+    fn get_result(&self, universe: &Universe) -> GameResult {
+        GameResult::from_scores(universe)
+    }
+}
@@ -0,0 +1,58 @@
+// *************************************************************************
+//
+// Copyright (c) 2025 Andrei Gramakov. All rights reserved.
+//
+// This file is licensed under the terms of the MIT license.
+// For a copy, see: https://opensource.org/licenses/MIT
+//
+// site:    https://agramakov.me
+// e-mail:  mail@agramakov.me
+//
+// *************************************************************************
+use crate::universe::Universe;
+use std::collections::BTreeMap;
+
+#[derive(Debug, Clone)]
+pub struct GameResult {
+    /// Score by player id
+    pub scores: BTreeMap<u32, u32>,
+    /// Player id of the winner, `None` for a draw
+    pub winner: Option<u32>,
+    pub elapsed_time: f32,
+}
+
+impl GameResult {
+    /// Result where the player with the highest score wins
+    pub fn from_scores(universe: &Universe) -> Self {
+        let scores: BTreeMap<u32, u32> = universe
+            .get_player_ids()
+            .into_iter()
+            .map(|id| (id, universe.get_player_score(id)))
+            .collect();
+
+        let best = scores.values().max().copied();
+        let mut leaders = scores.iter().filter(|(_, score)| Some(**score) == best);
+        let winner = match (leaders.next(), leaders.next()) {
+            (Some((id, _)), None) => Some(*id),
+            _ => None,
+        };
+
+        Self {
+            scores,
+            winner,
+            elapsed_time: universe.get_elapsed_time(),
+        }
+    }
+
+    pub fn get_score(&self, player_id: u32) -> u32 {
+        self.scores.get(&player_id).copied().unwrap_or(0)
+    }
+
+    pub fn get_scores_text(&self) -> String {
+        self.scores
+            .iter()
+            .map(|(id, score)| format!("P{}:{}", id, score))
+            .collect::<Vec<_>>()
+            .join(" ")
+    }
+}
@@ -0,0 +1,27 @@
+// *************************************************************************
+//
+// Copyright (c) 2025 Andrei Gramakov. All rights reserved.
+//
+// This file is licensed under the terms of the MIT license.
+// For a copy, see: https://opensource.org/licenses/MIT
+//
+// site:    https://agramakov.me
+// e-mail:  mail@agramakov.me
+//
+// *************************************************************************
+use super::GameResult;
+use crate::universe::Universe;
+
+/// Game mode consulted by the universe on every tick
+pub trait GameRules {
+    /// Called once per tick after the collisions are processed. Spawning,
+    /// timers and other mode specific logic goes here.
+    fn update(&mut self, universe: &mut Universe, delta_time: f32);
+
+    fn is_game_over(&self, universe: &Universe) -> bool;
+
+    /// Text shown on the OSD
+    fn get_status_text(&self, universe: &Universe) -> String;
+
+    fn get_result(&self, universe: &Universe) -> GameResult;
+}
@@ -0,0 +1,24 @@
+// *************************************************************************
+//
+// Copyright (c) 2025 Andrei Gramakov. All rights reserved.
+//
+// This file is licensed under the terms of the MIT license.
+// For a copy, see: https://opensource.org/licenses/MIT
+//
+// site:    https://agramakov.me
+// e-mail:  mail@agramakov.me
+//
+// *************************************************************************
+mod first_to;
+mod game_result;
+mod game_rules;
+mod respawn_on_score;
+mod survival;
+mod time_attack;
+
+pub use first_to::FirstTo;
+pub use game_result::GameResult;
+pub use game_rules::GameRules;
+pub use respawn_on_score::RespawnOnScore;
+pub use survival::Survival;
+pub use time_attack::TimeAttack;
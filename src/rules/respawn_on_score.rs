@@ -0,0 +1,37 @@
+// *************************************************************************
+//
+// Copyright (c) 2025 Andrei Gramakov. All rights reserved.
+//
+// This file is licensed under the terms of the MIT license.
+// For a copy, see: https://opensource.org/licenses/MIT
+//
+// site:    https://agramakov.me
+// e-mail:  mail@agramakov.me
+//
+// *************************************************************************
+use crate::universe::Universe;
+
+/// Spawn policy: a new random collectible for every point scored
+#[derive(Default)]
+pub struct RespawnOnScore {
+    last_total_score: u32,
+}
+
+impl RespawnOnScore {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    pub fn update(&mut self, universe: &mut Universe) {
+        let total_score: u32 = universe
+            .get_player_ids()
+            .into_iter()
+            .map(|id| universe.get_player_score(id))
+            .sum();
+
+        for _ in self.last_total_score..total_score {
+            universe.add_random_collectible();
+        }
+        self.last_total_score = total_score;
+    }
+}
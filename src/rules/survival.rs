@@ -0,0 +1,138 @@
+// *************************************************************************
+//
+// Copyright (c) 2025 Andrei Gramakov. All rights reserved.
+//
+// This file is licensed under the terms of the MIT license.
+// For a copy, see: https://opensource.org/licenses/MIT
+//
+// site:    https://agramakov.me
+// e-mail:  mail@agramakov.me
+//
+// *************************************************************************
+use super::{GameResult, GameRules, RespawnOnScore};
+use crate::universe::Universe;
+use std::collections::BTreeMap;
+
+/// Every player has to score again before the time limit or is eliminated,
+/// the last player left wins
+pub struct Survival {
+    /// Seconds a player survives without scoring
+    time_limit: f32,
+    respawn: RespawnOnScore,
+    /// Score and seconds left of the players still in the game, by player id
+    alive: BTreeMap<u32, (u32, f32)>,
+    /// Final score of the eliminated players, by player id
+    eliminated: BTreeMap<u32, u32>,
+    /// Number of players when the game started, 0 before the first update
+    player_count: usize,
+}
+
+impl Default for Survival {
+    fn default() -> Self {
+        Self::new(Survival::DEFAULT_TIME_LIMIT)
+    }
+}
+
+impl Survival {
+    pub const DEFAULT_TIME_LIMIT: f32 = 10.0;
+
+    pub fn new(time_limit: f32) -> Self {
+        Self {
+            time_limit,
+            respawn: RespawnOnScore::new(),
+            alive: BTreeMap::new(),
+            eliminated: BTreeMap::new(),
+            player_count: 0,
+        }
+    }
+
+    pub fn is_eliminated(&self, player_id: u32) -> bool {
+        self.eliminated.contains_key(&player_id)
+    }
+
+    /// Seconds the player has left to score, `None` if eliminated
+    pub fn get_time_left(&self, player_id: u32) -> Option<f32> {
+        self.alive.get(&player_id).map(|(_, time_left)| *time_left)
+    }
+
+    fn start(&mut self, universe: &Universe) {
+        for id in universe.get_player_ids() {
+            let score = universe.get_player_score(id);
+            self.alive.insert(id, (score, self.time_limit));
+        }
+        self.player_count = self.alive.len();
+    }
+
+    /// The player is removed from the world
+    fn eliminate(&mut self, universe: &mut Universe, player_id: u32) {
+        if let Some((score, _)) = self.alive.remove(&player_id) {
+            log::debug!("Player {} eliminated", player_id);
+            self.eliminated.insert(player_id, score);
+        }
+        if let Some(id) = universe.find_player(player_id) {
+            universe.despawn(id);
+        }
+    }
+}
+
+impl GameRules for Survival {
+    fn update(&mut self, universe: &mut Universe, delta_time: f32) {
+        if self.player_count == 0 {
+            self.start(universe);
+        }
+        let mut starved = Vec::new();
+        for (id, (score, time_left)) in self.alive.iter_mut() {
+            let new_score = universe.get_player_score(*id);
+            if new_score > *score {
+                *score = new_score;
+                *time_left = self.time_limit;
+            } else {
+                *time_left -= delta_time;
+            }
+            if *time_left <= 0.0 {
+                starved.push(*id);
+            }
+        }
+        for id in starved {
+            self.eliminate(universe, id);
+        }
+        self.respawn.update(universe);
+    }
+
+    /// Over when one player is left, or none in a single player game
+    fn is_game_over(&self, _universe: &Universe) -> bool {
+        self.player_count > 0 && self.alive.len() < self.player_count.min(2)
+    }
+
+    fn get_status_text(&self, universe: &Universe) -> String {
+        let players = self
+            .alive
+            .iter()
+            .map(|(id, (_, time_left))| format!("P{}:{}s", id, time_left.max(0.0).ceil() as u32))
+            .collect::<Vec<_>>()
+            .join(" ");
+        format!(
+            "SURVIVAL {}, {}",
+            players,
+            self.get_result(universe).get_scores_text()
+        )
+    }
+
+    /// The last player left wins, whatever the scores
+    fn get_result(&self, universe: &Universe) -> GameResult {
+        if self.player_count == 0 {
+            return GameResult::from_scores(universe);
+        }
+        let mut scores = self.eliminated.clone();
+        scores.extend(self.alive.iter().map(|(id, (score, _))| (*id, *score)));
+        let winner = match (self.player_count, self.alive.keys().next()) {
+            (2.., Some(id)) if self.alive.len() == 1 => Some(*id),
+            _ => None,
+        };
+        GameResult {
+            scores,
+            winner,
+            elapsed_time: universe.get_elapsed_time(),
+        }
+    }
+}
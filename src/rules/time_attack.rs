@@ -0,0 +1,64 @@
+// *************************************************************************
+//
+// Copyright (c) 2025 Andrei Gramakov. All rights reserved.
+//
+// This file is licensed under the terms of the MIT license.
+// For a copy, see: https://opensource.org/licenses/MIT
+//
+// site:    https://agramakov.me
+// e-mail:  mail@agramakov.me
+//
+// *************************************************************************
+use super::{GameResult, GameRules, RespawnOnScore};
+use crate::universe::Universe;
+
+/// Collect as much as possible before the time runs out
+pub struct TimeAttack {
+    duration: u32,
+    respawn: RespawnOnScore,
+}
+
+impl Default for TimeAttack {
+    fn default() -> Self {
+        Self::new(TimeAttack::DEFAULT_DURATION)
+    }
+}
+
+impl TimeAttack {
+    pub const DEFAULT_DURATION: u32 = 30;
+
+    /// `duration` of the game in seconds
+    pub fn new(duration: u32) -> Self {
+        Self {
+            duration,
+            respawn: RespawnOnScore::new(),
+        }
+    }
+
+    pub fn get_time_left(&self, universe: &Universe) -> u32 {
+        self.duration
+            .saturating_sub(universe.get_elapsed_time() as u32)
+    }
+}
+
+impl GameRules for TimeAttack {
+    fn update(&mut self, universe: &mut Universe, _delta_time: f32) {
+        self.respawn.update(universe);
+    }
+
+    fn is_game_over(&self, universe: &Universe) -> bool {
+        self.get_time_left(universe) == 0
+    }
+
+    fn get_status_text(&self, universe: &Universe) -> String {
+        format!(
+            "TIME: {}, {}",
+            self.get_time_left(universe),
+            GameResult::from_scores(universe).get_scores_text()
+        )
+    }
+
+    fn get_result(&self, universe: &Universe) -> GameResult {
+        GameResult::from_scores(universe)
+    }
+}
@@ -0,0 +1,23 @@
+// *************************************************************************
+//
+// Copyright (c) 2025 Andrei Gramakov. All rights reserved.
+//
+// This file is licensed under the terms of the MIT license.
+// For a copy, see: https://opensource.org/licenses/MIT
+//
+// site:    https://agramakov.me
+// e-mail:  mail@agramakov.me
+//
+// *************************************************************************
+
+/// How a body takes part in the collisions. Pairs without a dynamic body
+/// are never checked.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum BodyType {
+    /// Never moves, e.g. walls
+    Static,
+    /// Moved by the game logic, not pushed by collisions, e.g. collectibles
+    Kinematic,
+    /// Moved by forces and pushed by collisions, e.g. players
+    Dynamic,
+}
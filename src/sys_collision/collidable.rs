@@ -9,109 +9,163 @@
 // e-mail:  mail@agramakov.me
 //
 // *************************************************************************
-use crate::base::{DynamicAttributes, GameObjectAttributes};
-
-const RESTITUTION: f32 = 0.5;
+use super::{BodyType, CollidableAttributes, CollidableType, CollisionFilter, PhysicsMaterial};
+use crate::{
+    base::{DynamicAttributes, GameObjectAttributes},
+    entity::EntityId,
+};
+use parry2d::{
+    bounding_volume::{Aabb, BoundingVolume},
+    math::{Isometry, Vector},
+    query::{self, Contact, ShapeCastOptions},
+};
+
+/// Shapes closer than this still have a contact, so objects resting against
+/// each other after the overlap is resolved keep colliding
+const CONTACT_MARGIN: f32 = 0.01;
 
 pub trait Collidable {
     fn get_x(&self) -> f32;
     fn get_y(&self) -> f32;
     fn get_width(&self) -> f32;
     fn get_height(&self) -> f32;
-    fn borrow_mut_dynamic_attributes(&mut self) -> &mut DynamicAttributes;
     fn borrow_mut_base_object(&mut self) -> &mut GameObjectAttributes;
     fn borrow_base_object(&self) -> &GameObjectAttributes;
-    fn borrow_dynamic_attributes(&self) -> &DynamicAttributes;
-    fn get_collidable_type(&self) -> u32;
+    fn borrow_collidable_attributes(&self) -> &CollidableAttributes;
+    fn borrow_mut_collidable_attributes(&mut self) -> &mut CollidableAttributes;
+    fn get_collidable_type(&self) -> CollidableType;
     fn process_collision(&mut self, other: &dyn Collidable);
 
-    fn is_collides(&self, other: &dyn Collidable) -> bool {
-        let (self_x, self_y, self_width, self_height) = (
-            self.get_x(),
-            self.get_y(),
-            self.get_width(),
-            self.get_height(),
-        );
-
-        let (other_x, other_y, other_width, other_height) = (
-            other.get_x(),
-            other.get_y(),
-            other.get_width(),
-            other.get_height(),
-        );
-
-        self_x < other_x + other_width
-            && self_x + self_width > other_x
-            && self_y < other_y + other_height
-            && self_y + self_height > other_y
-    }
-
-    fn compensate_overlap(&mut self, other: &dyn Collidable) {
-        let self_obj = self.borrow_mut_base_object();
-        let other_obj = other.borrow_base_object();
-
-        let overlap_x = (self_obj.x + self_obj.width - other_obj.x)
-            .min(other_obj.x + other_obj.width - self_obj.x);
-        let overlap_y = (self_obj.y + self_obj.height - other_obj.y)
-            .min(other_obj.y + other_obj.height - self_obj.y);
-
-        if overlap_x < overlap_y {
-            // Resolve in the x direction
-            if self_obj.x < other_obj.x {
-                self_obj.x -= overlap_x; // Move self left
-            } else {
-                self_obj.x += overlap_x; // Move self right
-            }
-        } else {
-            // Resolve in the y direction
-            if self_obj.y < other_obj.y {
-                self_obj.y -= overlap_y; // Move self up
-            } else {
-                self_obj.y += overlap_y; // Move self down
-            }
-        }
+    /// Mass and velocity, `None` for objects that are never moved by
+    /// collisions
+    fn borrow_dynamic_attributes(&self) -> Option<&DynamicAttributes> {
+        None
     }
 
-    fn change_dynamics(&mut self, other: &dyn Collidable) {
-        let self_obj = self.borrow_base_object();
-        let other_obj = other.borrow_base_object();
-        // Calculate the collision normal
-        let collision_normal = {
-            let dx = (self_obj.x + self_obj.width / 2.0) - (other_obj.x + other_obj.width / 2.0);
-            let dy = (self_obj.y + self_obj.height / 2.0) - (other_obj.y + other_obj.height / 2.0);
-            (dx, dy)
-        };
+    fn borrow_mut_dynamic_attributes(&mut self) -> Option<&mut DynamicAttributes> {
+        None
+    }
 
-        // Normalize the collision normal
-        let length = (collision_normal.0.powi(2) + collision_normal.1.powi(2)).sqrt();
-        let (norm_x, norm_y) = if length != 0.0 {
-            (collision_normal.0 / length, collision_normal.1 / length)
-        } else {
-            (0.0, 0.0) // Prevent division by zero
-        };
+    fn get_body_type(&self) -> BodyType {
+        self.borrow_collidable_attributes().get_body_type()
+    }
 
-        let self_dyn = self.borrow_mut_dynamic_attributes();
-        // Calculate the self's velocity
-        let self_velocity_x = self_dyn.vel_x;
-        let self_velocity_y = self_dyn.vel_y;
+    fn get_collision_filter(&self) -> CollisionFilter {
+        self.borrow_collidable_attributes().get_filter()
+    }
 
-        // Calculate the relative velocity of self along the collision normal
-        let velocity_along_normal = self_velocity_x * norm_x + self_velocity_y * norm_y;
+    fn set_collision_filter(&mut self, filter: CollisionFilter) {
+        self.borrow_mut_collidable_attributes().set_filter(filter);
+    }
 
-        // Only resolve if the object is moving towards the other object
-        if velocity_along_normal > 0.0 {
-            return; // They are separating
-        }
+    fn get_physics_material(&self) -> PhysicsMaterial {
+        self.borrow_collidable_attributes().get_material()
+    }
+
+    fn set_physics_material(&mut self, material: PhysicsMaterial) {
+        self.borrow_mut_collidable_attributes()
+            .set_material(material);
+    }
+
+    fn is_sensor(&self) -> bool {
+        self.borrow_collidable_attributes().is_sensor()
+    }
+
+    fn is_ccd_enabled(&self) -> bool {
+        self.borrow_collidable_attributes().is_ccd_enabled()
+    }
 
-        // Coefficient of restitution (bounciness)
-        let restitution = RESTITUTION; // Adjust this value as needed
+    /// Sweep the object from its previous position, so it cannot pass
+    /// through thin static bodies when moving fast
+    fn set_ccd_enabled(&mut self, is_ccd_enabled: bool) {
+        self.borrow_mut_collidable_attributes()
+            .set_ccd_enabled(is_ccd_enabled);
+    }
+
+    /// The other object started to overlap, one of the two is a sensor
+    fn on_sensor_enter(&mut self, _other: &dyn Collidable) {}
+
+    /// The overlap with a sensor goes on, called every check
+    fn on_sensor_stay(&mut self, _other: &dyn Collidable) {}
 
-        // Calculate impulse scalar
-        let impulse_scalar = -(1.0 + restitution) * velocity_along_normal;
+    /// The overlap with a sensor ended, the other object may be deleted
+    fn on_sensor_exit(&mut self, _other_id: EntityId, _other_type: CollidableType) {}
+
+    /// Position of the shape, the centre of the bounding box
+    fn get_isometry(&self) -> Isometry<f32> {
+        Isometry::translation(
+            self.get_x() + self.get_width() / 2.0,
+            self.get_y() + self.get_height() / 2.0,
+        )
+    }
+
+    /// Bounding box of the shape, used by the broad phase
+    fn get_aabb(&self) -> Aabb {
+        self.borrow_collidable_attributes()
+            .get_parry_shape()
+            .compute_aabb(&self.get_isometry())
+    }
+
+    /// Movement since the previous tick
+    fn get_motion(&self) -> Vector<f32> {
+        let base = self.borrow_base_object();
+        Vector::new(base.x - base.prev_x, base.y - base.prev_y)
+    }
 
-        // Apply impulse to self's velocity
-        self_dyn.vel_x += impulse_scalar * norm_x;
-        self_dyn.vel_y += impulse_scalar * norm_y;
+    /// Bounding box of the whole movement since the previous tick
+    fn get_swept_aabb(&self) -> Aabb {
+        let aabb = self.get_aabb();
+        let motion = self.get_motion();
+        aabb.merged(&Aabb::new(aabb.mins - motion, aabb.maxs - motion))
+    }
+
+    /// Fraction of the movement since the previous tick, at which the object
+    /// first touches the other one, `None` if it does not touch it on the way
+    fn get_time_of_impact(&self, other: &dyn Collidable) -> Option<f32> {
+        let motion = self.get_motion();
+        let start = self.get_isometry().translation.vector - motion;
+        let options = ShapeCastOptions {
+            max_time_of_impact: 1.0,
+            target_distance: 0.0,
+            stop_at_penetration: false,
+            compute_impact_geometry_on_penetration: false,
+        };
+        query::cast_shapes(
+            &Isometry::translation(start.x, start.y),
+            &motion,
+            self.borrow_collidable_attributes().get_parry_shape(),
+            &other.get_isometry(),
+            &Vector::zeros(),
+            other.borrow_collidable_attributes().get_parry_shape(),
+            options,
+        )
+        .unwrap_or_else(|e| {
+            log::warn!("Unsupported collision shapes: {}", e);
+            None
+        })
+        .map(|hit| hit.time_of_impact)
+    }
+
+    /// Closest points and the normal pointing from self to the other object,
+    /// `None` if the shapes are further apart than the contact margin
+    fn get_contact(&self, other: &dyn Collidable) -> Option<Contact> {
+        query::contact(
+            &self.get_isometry(),
+            self.borrow_collidable_attributes().get_parry_shape(),
+            &other.get_isometry(),
+            other.borrow_collidable_attributes().get_parry_shape(),
+            CONTACT_MARGIN,
+        )
+        .unwrap_or_else(|e| {
+            log::warn!("Unsupported collision shapes: {}", e);
+            None
+        })
+    }
+
+    /// Touching shapes do not collide, they have to overlap
+    fn is_collides(&self, other: &dyn Collidable) -> bool {
+        self.get_contact(other)
+            .is_some_and(|contact| contact.dist < 0.0)
     }
 
     fn try_collide(&mut self, other: &dyn Collidable) {
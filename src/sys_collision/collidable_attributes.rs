@@ -0,0 +1,160 @@
+// *************************************************************************
+//
+// Copyright (c) 2025 Andrei Gramakov. All rights reserved.
+//
+// This file is licensed under the terms of the MIT license.
+// For a copy, see: https://opensource.org/licenses/MIT
+//
+// site:    https://agramakov.me
+// e-mail:  mail@agramakov.me
+//
+// *************************************************************************
+use super::{BodyType, CollisionFilter, CollisionShape, PhysicsMaterial};
+use macroquad::{
+    color::Color,
+    math::Vec2,
+    shapes::{draw_circle, draw_triangle},
+};
+use parry2d::{
+    math::{Isometry, Point},
+    shape::{Shape, SharedShape},
+};
+
+pub struct CollidableAttributes {
+    body_type: BodyType,
+    filter: CollisionFilter,
+    material: PhysicsMaterial,
+    /// Sensors report overlaps without a physical response
+    is_sensor: bool,
+    /// Continuous collision detection against static bodies, for fast objects
+    is_ccd_enabled: bool,
+    shape: CollisionShape,
+    /// Built from `shape` once, used by the contact queries
+    parry_shape: SharedShape,
+}
+
+impl CollidableAttributes {
+    pub fn new(body_type: BodyType, shape: CollisionShape, size_wh: (f32, f32)) -> Self {
+        let parry_shape = Self::build_parry_shape(&shape, size_wh);
+        Self {
+            body_type,
+            filter: CollisionFilter::default(),
+            material: PhysicsMaterial::default(),
+            is_sensor: false,
+            is_ccd_enabled: false,
+            shape,
+            parry_shape,
+        }
+    }
+
+    pub fn get_body_type(&self) -> BodyType {
+        self.body_type
+    }
+
+    pub fn get_filter(&self) -> CollisionFilter {
+        self.filter
+    }
+
+    pub fn set_filter(&mut self, filter: CollisionFilter) {
+        self.filter = filter;
+    }
+
+    pub fn get_material(&self) -> PhysicsMaterial {
+        self.material
+    }
+
+    pub fn set_material(&mut self, material: PhysicsMaterial) {
+        self.material = material;
+    }
+
+    pub fn is_sensor(&self) -> bool {
+        self.is_sensor
+    }
+
+    pub fn set_sensor(&mut self, is_sensor: bool) {
+        self.is_sensor = is_sensor;
+    }
+
+    pub fn is_ccd_enabled(&self) -> bool {
+        self.is_ccd_enabled
+    }
+
+    pub fn set_ccd_enabled(&mut self, is_ccd_enabled: bool) {
+        self.is_ccd_enabled = is_ccd_enabled;
+    }
+
+    pub fn set_shape(&mut self, shape: CollisionShape, size_wh: (f32, f32)) {
+        self.parry_shape = Self::build_parry_shape(&shape, size_wh);
+        self.shape = shape;
+    }
+
+    pub fn get_shape(&self) -> &CollisionShape {
+        &self.shape
+    }
+
+    pub fn get_parry_shape(&self) -> &dyn Shape {
+        self.parry_shape.as_ref()
+    }
+
+    /// Degenerated shapes fall back to the bounding box
+    fn build_parry_shape(shape: &CollisionShape, size_wh: (f32, f32)) -> SharedShape {
+        shape.to_parry_shape(size_wh).unwrap_or_else(|| {
+            log::warn!("Invalid collision shape {:?}, using a rectangle", shape);
+            SharedShape::cuboid(size_wh.0 / 2.0, size_wh.1 / 2.0)
+        })
+    }
+
+    /// Draw the shape centred at `center_xy`
+    pub fn draw(&self, center_xy: (f32, f32), scale: f32, color: Color) {
+        let position = Isometry::translation(center_xy.0, center_xy.1);
+        draw_parry_shape(self.get_parry_shape(), &position, scale, color);
+    }
+}
+
+fn draw_parry_shape(shape: &dyn Shape, position: &Isometry<f32>, scale: f32, color: Color) {
+    let to_screen = |point: Point<f32>| {
+        let point = position * point;
+        Vec2::new(point.x * scale, point.y * scale)
+    };
+
+    if let Some(ball) = shape.as_ball() {
+        let center = to_screen(Point::origin());
+        draw_circle(center.x, center.y, ball.radius * scale, color);
+    } else if let Some(capsule) = shape.as_capsule() {
+        let radius = capsule.radius * scale;
+        let (a, b) = (to_screen(capsule.segment.a), to_screen(capsule.segment.b));
+        draw_circle(a.x, a.y, radius, color);
+        draw_circle(b.x, b.y, radius, color);
+        let side = (b - a).perp().normalize_or_zero() * radius;
+        draw_triangle(a + side, b + side, b - side, color);
+        draw_triangle(a + side, b - side, a - side, color);
+    } else if let Some(cuboid) = shape.as_cuboid() {
+        draw_polygon(&cuboid.to_polyline(), &to_screen, color);
+    } else if let Some(polygon) = shape.as_convex_polygon() {
+        draw_polygon(polygon.points(), &to_screen, color);
+    } else if let Some(compound) = shape.as_compound() {
+        for (part_position, part) in compound.shapes() {
+            draw_parry_shape(part.as_ref(), &(position * part_position), scale, color);
+        }
+    } else {
+        // Only the shapes created from `CollisionShape` are drawn
+        let aabb = shape.compute_aabb(position);
+        let center = aabb.center();
+        draw_parry_shape(
+            &parry2d::shape::Cuboid::new(aabb.half_extents()),
+            &Isometry::translation(center.x, center.y),
+            scale,
+            color,
+        );
+    }
+}
+
+/// Triangle fan of a convex polygon
+fn draw_polygon(points: &[Point<f32>], to_screen: &dyn Fn(Point<f32>) -> Vec2, color: Color) {
+    if let Some((first, rest)) = points.split_first() {
+        let first = to_screen(*first);
+        for pair in rest.windows(2) {
+            draw_triangle(first, to_screen(pair[0]), to_screen(pair[1]), color);
+        }
+    }
+}
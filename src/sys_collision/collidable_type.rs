@@ -0,0 +1,118 @@
+// *************************************************************************
+//
+// Copyright (c) 2025 Andrei Gramakov. All rights reserved.
+//
+// This file is licensed under the terms of the MIT license.
+// For a copy, see: https://opensource.org/licenses/MIT
+//
+// site:    https://agramakov.me
+// e-mail:  mail@agramakov.me
+//
+// *************************************************************************
+use std::{fmt, sync::Mutex};
+
+/// Category of a collidable object, e.g. to react to players only. The
+/// built-in objects use the constants, games register their own types at
+/// startup.
+///
+/// The registry is global to the process, not per `Universe`: two universes
+/// share the type ids, a type registered for one of them exists in the other
+/// one too with the same id, and a new universe does not reset the registry.
+#[derive(Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub struct CollidableType(u32);
+
+/// Names of the registered types, the id of a type is its index plus
+/// `CollidableType::FIRST_CUSTOM`
+static REGISTERED_NAMES: Mutex<Vec<String>> = Mutex::new(Vec::new());
+
+impl CollidableType {
+    pub const UNKNOWN: Self = Self(0);
+    pub const SOLID_OBSTACLE: Self = Self(1);
+    pub const PLAYER: Self = Self(2);
+    pub const COLLECTABLE: Self = Self(3);
+    pub const ZONE: Self = Self(4);
+    /// Ids of the registered types start here
+    const FIRST_CUSTOM: u32 = 256;
+
+    const BUILT_IN: [(Self, &'static str); 5] = [
+        (Self::UNKNOWN, "unknown"),
+        (Self::SOLID_OBSTACLE, "solid_obstacle"),
+        (Self::PLAYER, "player"),
+        (Self::COLLECTABLE, "collectable"),
+        (Self::ZONE, "zone"),
+    ];
+
+    /// New type with the name, registering a known name returns its type.
+    /// The ids follow the order of registration, they can differ between
+    /// runs of the game.
+    pub fn register(name: &str) -> Self {
+        let mut names = REGISTERED_NAMES.lock().unwrap();
+        if let Some(collidable_type) = Self::find_in(&names, name) {
+            return collidable_type;
+        }
+        names.push(name.to_string());
+        let collidable_type = Self(Self::FIRST_CUSTOM + names.len() as u32 - 1);
+        log::debug!(
+            "Collidable type {} registered as {}",
+            name,
+            collidable_type.0
+        );
+        collidable_type
+    }
+
+    /// Built-in or registered type by its name
+    pub fn find(name: &str) -> Option<Self> {
+        Self::find_in(&REGISTERED_NAMES.lock().unwrap(), name)
+    }
+
+    fn find_in(names: &[String], name: &str) -> Option<Self> {
+        if let Some((collidable_type, _)) = Self::BUILT_IN.iter().find(|(_, n)| *n == name) {
+            return Some(*collidable_type);
+        }
+        names
+            .iter()
+            .position(|n| n == name)
+            .map(|index| Self(Self::FIRST_CUSTOM + index as u32))
+    }
+
+    /// Numeric id, e.g. for the recordings
+    pub fn get_id(&self) -> u32 {
+        self.0
+    }
+
+    /// Ids of the types not registered yet are kept as they are
+    pub fn from_id(id: u32) -> Self {
+        Self(id)
+    }
+
+    /// "unknown" followed by the id for the types not registered
+    pub fn get_name(&self) -> String {
+        if let Some((_, name)) = Self::BUILT_IN.iter().find(|(t, _)| t == self) {
+            return name.to_string();
+        }
+        let names = REGISTERED_NAMES.lock().unwrap();
+        self.0
+            .checked_sub(Self::FIRST_CUSTOM)
+            .and_then(|index| names.get(index as usize))
+            .cloned()
+            .unwrap_or_else(|| format!("unknown({})", self.0))
+    }
+}
+
+impl fmt::Debug for CollidableType {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{}", self.get_name())
+    }
+}
+
+impl fmt::Display for CollidableType {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{}", self.get_name())
+    }
+}
+
+impl Default for CollidableType {
+    fn default() -> Self {
+        Self::UNKNOWN
+    }
+}
@@ -0,0 +1,39 @@
+// *************************************************************************
+//
+// Copyright (c) 2025 Andrei Gramakov. All rights reserved.
+//
+// This file is licensed under the terms of the MIT license.
+// For a copy, see: https://opensource.org/licenses/MIT
+//
+// site:    https://agramakov.me
+// e-mail:  mail@agramakov.me
+//
+// *************************************************************************
+
+/// Which objects interact, two objects interact only if each one is on a
+/// layer in the mask of the other
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct CollisionFilter {
+    /// Layers the object is on, one bit per layer
+    pub layers: u32,
+    /// Layers the object interacts with
+    pub mask: u32,
+}
+
+impl CollisionFilter {
+    pub const ALL: u32 = u32::MAX;
+
+    pub fn new(layers: u32, mask: u32) -> Self {
+        Self { layers, mask }
+    }
+
+    pub fn interacts_with(&self, other: &CollisionFilter) -> bool {
+        self.layers & other.mask != 0 && other.layers & self.mask != 0
+    }
+}
+
+impl Default for CollisionFilter {
+    fn default() -> Self {
+        Self::new(Self::ALL, Self::ALL)
+    }
+}
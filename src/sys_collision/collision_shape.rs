@@ -0,0 +1,155 @@
+// *************************************************************************
+//
+// Copyright (c) 2025 Andrei Gramakov. All rights reserved.
+//
+// This file is licensed under the terms of the MIT license.
+// For a copy, see: https://opensource.org/licenses/MIT
+//
+// site:    https://agramakov.me
+// e-mail:  mail@agramakov.me
+//
+// *************************************************************************
+use parry2d::{
+    math::{Isometry, Point, Vector},
+    shape::SharedShape,
+};
+
+/// Shape of a collidable object, centred at the centre of its bounding box.
+/// Positions and points are relative to that centre.
+#[derive(Debug, Clone)]
+pub enum CollisionShape {
+    /// Fills the bounding box
+    Rectangle,
+    Cuboid {
+        half_extents_wh: (f32, f32),
+    },
+    Ball {
+        radius: f32,
+    },
+    /// Vertical capsule, `half_height` excludes the round caps
+    Capsule {
+        half_height: f32,
+        radius: f32,
+    },
+    /// The convex hull of the points is used
+    ConvexPolygon {
+        points: Vec<(f32, f32)>,
+    },
+    /// Shapes placed at an offset and rotated by an angle in radians
+    Compound {
+        parts: Vec<((f32, f32), f32, CollisionShape)>,
+    },
+}
+
+impl CollisionShape {
+    /// Polygon of a box rotated by `angle` in radians, for angled walls
+    pub fn rotated_rectangle(size_wh: (f32, f32), angle: f32) -> Self {
+        CollisionShape::Compound {
+            parts: vec![(
+                (0.0, 0.0),
+                angle,
+                CollisionShape::Cuboid {
+                    half_extents_wh: (size_wh.0 / 2.0, size_wh.1 / 2.0),
+                },
+            )],
+        }
+    }
+
+    /// The shape stretched by the factors, e.g. when the object is resized.
+    /// Balls and capsules stay round and use the smaller factor for the
+    /// radius. Rotated boxes and polygons of a compound become polygons
+    /// stretched along the axes of the object, the other rotated parts are
+    /// scaled along their own axes.
+    pub fn scaled(&self, scale_xy: (f32, f32)) -> Self {
+        let (sx, sy) = scale_xy;
+        match self {
+            CollisionShape::Rectangle => CollisionShape::Rectangle,
+            CollisionShape::Cuboid { half_extents_wh } => CollisionShape::Cuboid {
+                half_extents_wh: (half_extents_wh.0 * sx, half_extents_wh.1 * sy),
+            },
+            CollisionShape::Ball { radius } => CollisionShape::Ball {
+                radius: radius * sx.min(sy),
+            },
+            CollisionShape::Capsule {
+                half_height,
+                radius,
+            } => {
+                let scaled_radius = radius * sx.min(sy);
+                CollisionShape::Capsule {
+                    half_height: ((half_height + radius) * sy - scaled_radius).max(0.0),
+                    radius: scaled_radius,
+                }
+            }
+            CollisionShape::ConvexPolygon { points } => CollisionShape::ConvexPolygon {
+                points: points.iter().map(|(x, y)| (x * sx, y * sy)).collect(),
+            },
+            CollisionShape::Compound { parts } => CollisionShape::Compound {
+                parts: parts
+                    .iter()
+                    .map(|((x, y), angle, part)| {
+                        let offset_xy = (x * sx, y * sy);
+                        match part.get_rotated_points(*angle) {
+                            Some(points) if *angle != 0.0 && sx != sy => (
+                                offset_xy,
+                                0.0,
+                                CollisionShape::ConvexPolygon { points }.scaled(scale_xy),
+                            ),
+                            _ => (offset_xy, *angle, part.scaled(scale_xy)),
+                        }
+                    })
+                    .collect(),
+            },
+        }
+    }
+
+    /// Corners of a box or points of a polygon rotated by `angle`, `None`
+    /// for the other shapes
+    fn get_rotated_points(&self, angle: f32) -> Option<Vec<(f32, f32)>> {
+        let points = match self {
+            CollisionShape::Cuboid {
+                half_extents_wh: (hw, hh),
+            } => vec![(-hw, -hh), (*hw, -hh), (*hw, *hh), (-hw, *hh)],
+            CollisionShape::ConvexPolygon { points } => points.clone(),
+            _ => return None,
+        };
+        let (sin, cos) = angle.sin_cos();
+        Some(
+            points
+                .into_iter()
+                .map(|(x, y)| (x * cos - y * sin, x * sin + y * cos))
+                .collect(),
+        )
+    }
+
+    /// `None` for polygons without area
+    pub fn to_parry_shape(&self, size_wh: (f32, f32)) -> Option<SharedShape> {
+        let shape = match self {
+            CollisionShape::Rectangle => SharedShape::cuboid(size_wh.0 / 2.0, size_wh.1 / 2.0),
+            CollisionShape::Cuboid { half_extents_wh } => {
+                SharedShape::cuboid(half_extents_wh.0, half_extents_wh.1)
+            }
+            CollisionShape::Ball { radius } => SharedShape::ball(*radius),
+            CollisionShape::Capsule {
+                half_height,
+                radius,
+            } => SharedShape::capsule_y(*half_height, *radius),
+            CollisionShape::ConvexPolygon { points } => {
+                let points: Vec<Point<f32>> =
+                    points.iter().map(|(x, y)| Point::new(*x, *y)).collect();
+                SharedShape::convex_hull(&points)?
+            }
+            CollisionShape::Compound { parts } => {
+                let mut shapes = Vec::new();
+                for ((x, y), angle, part) in parts {
+                    let position = Isometry::new(Vector::new(*x, *y), *angle);
+                    shapes.push((position, part.to_parry_shape(size_wh)?));
+                }
+                if shapes.is_empty() {
+                    return None;
+                }
+                SharedShape::compound(shapes)
+            }
+        };
+        Some(shape)
+    }
+}
@@ -0,0 +1,93 @@
+// *************************************************************************
+//
+// Copyright (c) 2025 Andrei Gramakov. All rights reserved.
+//
+// This file is licensed under the terms of the MIT license.
+// For a copy, see: https://opensource.org/licenses/MIT
+//
+// site:    https://agramakov.me
+// e-mail:  mail@agramakov.me
+//
+// *************************************************************************
+use super::{BodyType, Collidable};
+use parry2d::query::Contact;
+
+/// Inverse mass and velocity, static and kinematic bodies and objects
+/// without dynamic attributes do not move on collisions, as if their mass
+/// was infinite
+fn get_motion(object: &dyn Collidable) -> (f32, (f32, f32)) {
+    match (object.get_body_type(), object.borrow_dynamic_attributes()) {
+        (BodyType::Dynamic, Some(dynamic)) => (1.0 / dynamic.mass, (dynamic.vel_x, dynamic.vel_y)),
+        _ => (0.0, (0.0, 0.0)),
+    }
+}
+
+fn apply_impulse(object: &mut dyn Collidable, inverse_mass: f32, impulse: (f32, f32)) {
+    if inverse_mass <= 0.0 {
+        return;
+    }
+    if let Some(dynamic) = object.borrow_mut_dynamic_attributes() {
+        dynamic.vel_x += impulse.0 * inverse_mass;
+        dynamic.vel_y += impulse.1 * inverse_mass;
+    }
+}
+
+fn move_by(object: &mut dyn Collidable, offset: (f32, f32)) {
+    let base = object.borrow_mut_base_object();
+    base.x += offset.0;
+    base.y += offset.1;
+}
+
+/// Separate two overlapping bodies and exchange the impulses along the
+/// contact normal and the contact surface. The contact normal points from
+/// `a` to `b`.
+pub fn resolve_contact(a: &mut dyn Collidable, b: &mut dyn Collidable, contact: &Contact) {
+    let (inv_mass_a, vel_a) = get_motion(a);
+    let (inv_mass_b, vel_b) = get_motion(b);
+    let inv_mass_sum = inv_mass_a + inv_mass_b;
+    if inv_mass_sum == 0.0 {
+        return;
+    }
+    let (nx, ny) = (contact.normal1.x, contact.normal1.y);
+
+    // The lighter body moves more
+    let depth = (-contact.dist).max(0.0);
+    let share_a = depth * inv_mass_a / inv_mass_sum;
+    let share_b = depth * inv_mass_b / inv_mass_sum;
+    move_by(a, (-nx * share_a, -ny * share_a));
+    move_by(b, (nx * share_b, ny * share_b));
+
+    // Relative velocity of b seen from a
+    let (rel_x, rel_y) = (vel_b.0 - vel_a.0, vel_b.1 - vel_a.1);
+    let vel_along_normal = rel_x * nx + rel_y * ny;
+    if vel_along_normal > 0.0 {
+        return; // They are separating
+    }
+
+    let material = a.get_physics_material().combine(&b.get_physics_material());
+    let normal_impulse = -(1.0 + material.restitution) * vel_along_normal / inv_mass_sum;
+    apply_impulse(a, inv_mass_a, (-nx * normal_impulse, -ny * normal_impulse));
+    apply_impulse(b, inv_mass_b, (nx * normal_impulse, ny * normal_impulse));
+
+    // Friction against the sliding along the surface, limited by the normal
+    // impulse
+    let (tx, ty) = (rel_x - vel_along_normal * nx, rel_y - vel_along_normal * ny);
+    let tangent_length = (tx * tx + ty * ty).sqrt();
+    if tangent_length <= f32::EPSILON || material.friction == 0.0 {
+        return;
+    }
+    let (tx, ty) = (tx / tangent_length, ty / tangent_length);
+    let max_friction = material.friction * normal_impulse;
+    let friction_impulse =
+        (-(rel_x * tx + rel_y * ty) / inv_mass_sum).clamp(-max_friction, max_friction);
+    apply_impulse(
+        a,
+        inv_mass_a,
+        (-tx * friction_impulse, -ty * friction_impulse),
+    );
+    apply_impulse(
+        b,
+        inv_mass_b,
+        (tx * friction_impulse, ty * friction_impulse),
+    );
+}
@@ -9,8 +9,27 @@
 // e-mail:  mail@agramakov.me
 //
 // *************************************************************************
+mod body_type;
 mod collidable;
+mod collidable_attributes;
+mod collidable_type;
+mod collision_filter;
+mod collision_shape;
+mod contact_solver;
+mod physics_material;
+mod query_filter;
+mod ray_hit;
+mod spatial_grid;
+#[allow(clippy::module_inception)]
 mod sys_collision;
 
+pub use body_type::BodyType;
 pub use collidable::Collidable;
-pub use sys_collision::SysCollision;
+pub use collidable_attributes::CollidableAttributes;
+pub use collidable_type::CollidableType;
+pub use collision_filter::CollisionFilter;
+pub use collision_shape::CollisionShape;
+pub use physics_material::PhysicsMaterial;
+pub use query_filter::QueryFilter;
+pub use ray_hit::RayHit;
+pub use sys_collision::{PairFilter, SysCollision};
@@ -0,0 +1,46 @@
+// *************************************************************************
+//
+// Copyright (c) 2025 Andrei Gramakov. All rights reserved.
+//
+// This file is licensed under the terms of the MIT license.
+// For a copy, see: https://opensource.org/licenses/MIT
+//
+// site:    https://agramakov.me
+// e-mail:  mail@agramakov.me
+//
+// *************************************************************************
+
+/// Surface properties used by the contact solver
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct PhysicsMaterial {
+    /// Bounciness, 0.0 stops along the contact normal, 1.0 keeps the speed
+    pub restitution: f32,
+    /// Coulomb friction along the contact surface, 0.0 slides freely
+    pub friction: f32,
+}
+
+impl PhysicsMaterial {
+    pub const DEFAULT_RESTITUTION: f32 = 0.5;
+
+    pub fn new(restitution: f32, friction: f32) -> Self {
+        Self {
+            restitution,
+            friction,
+        }
+    }
+
+    /// Material of a contact, the bouncier body wins and the friction is the
+    /// geometric mean
+    pub fn combine(&self, other: &PhysicsMaterial) -> PhysicsMaterial {
+        PhysicsMaterial::new(
+            self.restitution.max(other.restitution),
+            (self.friction * other.friction).sqrt(),
+        )
+    }
+}
+
+impl Default for PhysicsMaterial {
+    fn default() -> Self {
+        Self::new(Self::DEFAULT_RESTITUTION, 0.0)
+    }
+}
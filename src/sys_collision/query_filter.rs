@@ -0,0 +1,58 @@
+// *************************************************************************
+//
+// Copyright (c) 2025 Andrei Gramakov. All rights reserved.
+//
+// This file is licensed under the terms of the MIT license.
+// For a copy, see: https://opensource.org/licenses/MIT
+//
+// site:    https://agramakov.me
+// e-mail:  mail@agramakov.me
+//
+// *************************************************************************
+use super::{Collidable, CollisionFilter};
+use crate::entity::EntityId;
+
+/// Which objects a spatial query reports
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct QueryFilter {
+    /// Objects on any of these layers are reported
+    pub mask: u32,
+    pub include_sensors: bool,
+    /// E.g. the object looking around
+    pub excluded_id: Option<EntityId>,
+}
+
+impl QueryFilter {
+    /// Sensors are included
+    pub fn new(mask: u32) -> Self {
+        Self {
+            mask,
+            include_sensors: true,
+            excluded_id: None,
+        }
+    }
+
+    /// Solid objects only, e.g. for the line of sight
+    pub fn solid(mask: u32) -> Self {
+        Self {
+            include_sensors: false,
+            ..Self::new(mask)
+        }
+    }
+
+    pub fn set_excluded_id(&mut self, excluded_id: Option<EntityId>) {
+        self.excluded_id = excluded_id;
+    }
+
+    pub fn accepts(&self, object: &dyn Collidable) -> bool {
+        object.get_collision_filter().layers & self.mask != 0
+            && (self.include_sensors || !object.is_sensor())
+            && self.excluded_id != Some(object.borrow_base_object().id)
+    }
+}
+
+impl Default for QueryFilter {
+    fn default() -> Self {
+        Self::new(CollisionFilter::ALL)
+    }
+}
@@ -0,0 +1,25 @@
+// *************************************************************************
+//
+// Copyright (c) 2025 Andrei Gramakov. All rights reserved.
+//
+// This file is licensed under the terms of the MIT license.
+// For a copy, see: https://opensource.org/licenses/MIT
+//
+// site:    https://agramakov.me
+// e-mail:  mail@agramakov.me
+//
+// *************************************************************************
+use super::CollidableType;
+use crate::entity::EntityId;
+
+/// The first object hit by a ray
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct RayHit {
+    pub object_id: EntityId,
+    pub object_type: CollidableType,
+    pub point_xy: (f32, f32),
+    /// Normal of the surface at the hit point
+    pub normal_xy: (f32, f32),
+    /// From the ray origin to the hit point
+    pub distance: f32,
+}
@@ -0,0 +1,93 @@
+// *************************************************************************
+//
+// Copyright (c) 2025 Andrei Gramakov. All rights reserved.
+//
+// This file is licensed under the terms of the MIT license.
+// For a copy, see: https://opensource.org/licenses/MIT
+//
+// site:    https://agramakov.me
+// e-mail:  mail@agramakov.me
+//
+// *************************************************************************
+use parry2d::bounding_volume::Aabb;
+use std::collections::HashMap;
+
+/// Uniform grid of object indices for the broad phase
+pub struct SpatialGrid {
+    cell_size: f32,
+    cells: HashMap<(i32, i32), Vec<usize>>,
+    /// Lowest and highest cell with objects, queries do not look further
+    bounds: Option<((i32, i32), (i32, i32))>,
+}
+
+impl SpatialGrid {
+    /// Panics if `cell_size` is not a positive number
+    pub fn new(cell_size: f32) -> Self {
+        assert!(
+            cell_size > 0.0 && cell_size.is_finite(),
+            "invalid cell size {}",
+            cell_size
+        );
+        Self {
+            cell_size,
+            cells: HashMap::new(),
+            bounds: None,
+        }
+    }
+
+    pub fn get_cell_size(&self) -> f32 {
+        self.cell_size
+    }
+
+    pub fn clear(&mut self) {
+        self.cells.clear();
+        self.bounds = None;
+    }
+
+    pub fn insert(&mut self, index: usize, aabb: &Aabb) {
+        let (min, max) = self.get_cell_range(aabb);
+        self.bounds = Some(match self.bounds {
+            Some((low, high)) => (
+                (low.0.min(min.0), low.1.min(min.1)),
+                (high.0.max(max.0), high.1.max(max.1)),
+            ),
+            None => (min, max),
+        });
+        for cx in min.0..=max.0 {
+            for cy in min.1..=max.1 {
+                self.cells.entry((cx, cy)).or_default().push(index);
+            }
+        }
+    }
+
+    /// Indices of the objects in the cells touched by the box, sorted. The
+    /// box is cut to the cells with objects, so huge boxes stay cheap.
+    pub fn query(&self, aabb: &Aabb, found: &mut Vec<usize>) {
+        found.clear();
+        let Some((low, high)) = self.bounds else {
+            return;
+        };
+        let (min, max) = self.get_cell_range(aabb);
+        let (min, max) = (
+            (min.0.max(low.0), min.1.max(low.1)),
+            (max.0.min(high.0), max.1.min(high.1)),
+        );
+        for cx in min.0..=max.0 {
+            for cy in min.1..=max.1 {
+                if let Some(cell) = self.cells.get(&(cx, cy)) {
+                    found.extend_from_slice(cell);
+                }
+            }
+        }
+        found.sort_unstable();
+        found.dedup();
+    }
+
+    fn get_cell_range(&self, aabb: &Aabb) -> ((i32, i32), (i32, i32)) {
+        let cell = |value: f32| (value / self.cell_size).floor() as i32;
+        (
+            (cell(aabb.mins.x), cell(aabb.mins.y)),
+            (cell(aabb.maxs.x), cell(aabb.maxs.y)),
+        )
+    }
+}
@@ -1,6 +1,4 @@
-use crate::sys_event::SysEvent;
-
-/// *************************************************************************
+// *************************************************************************
 //
 // Copyright (c) 2025 Andrei Gramakov. All rights reserved.
 //
@@ -11,71 +9,650 @@ use crate::sys_event::SysEvent;
 // e-mail:  mail@agramakov.me
 //
 // *************************************************************************
-use super::Collidable;
-use std::sync::{Arc, Mutex};
+use super::{
+    contact_solver, spatial_grid::SpatialGrid, BodyType, Collidable, CollidableType,
+    CollisionFilter, QueryFilter, RayHit,
+};
+use crate::{
+    entity::{EntityId, EntityStore},
+    sys_event::{Event, Publisher, SysEvent},
+};
+use parry2d::{
+    bounding_volume::{Aabb, BoundingVolume},
+    math::{Point, Vector},
+    query::{Contact, Ray},
+};
+use std::{
+    collections::BTreeMap,
+    sync::{Arc, Mutex},
+};
+
+/// Ids of the colliding objects, the lower one first
+type PairKey = (EntityId, EntityId);
+
+/// Decides if two objects interact, after their collision filters accepted
+/// each other
+pub type PairFilter = Box<dyn Fn(&dyn Collidable, &dyn Collidable) -> bool + Send>;
+
+/// What the broad phase needs to know about an object
+struct BroadPhaseEntry {
+    id: EntityId,
+    aabb: Aabb,
+    body_type: BodyType,
+    filter: CollisionFilter,
+    is_sensor: bool,
+}
+
+impl BroadPhaseEntry {
+    fn new(id: EntityId, object: &dyn Collidable) -> Self {
+        Self {
+            id,
+            aabb: object.get_aabb(),
+            body_type: object.get_body_type(),
+            filter: object.get_collision_filter(),
+            is_sensor: object.is_sensor(),
+        }
+    }
+
+    /// Pairs without a dynamic body, pairs of sensors and pairs rejected by
+    /// the filters are skipped
+    fn accepts(&self, other: &BroadPhaseEntry) -> bool {
+        (self.body_type == BodyType::Dynamic || other.body_type == BodyType::Dynamic)
+            && !(self.is_sensor && other.is_sensor)
+            && self.filter.interacts_with(&other.filter)
+    }
+}
 
-pub struct CollidableObjectWrap {
-    object: Arc<Mutex<dyn Collidable>>,
+/// Collision of a pair, `a` is the object with the lower id
+struct PairContact {
+    type_a: CollidableType,
+    type_b: CollidableType,
+    normal_xy: (f32, f32),
+    depth: f32,
+    /// Set if one of the objects is a sensor
+    sensor_id: Option<EntityId>,
 }
 
+impl PairContact {
+    /// Id and type of the sensor, then of the other object
+    fn get_sensor_pair(
+        &self,
+        key: PairKey,
+    ) -> Option<((EntityId, CollidableType), (EntityId, CollidableType))> {
+        let (a, b) = ((key.0, self.type_a), (key.1, self.type_b));
+        match self.sensor_id {
+            Some(id) if id == key.0 => Some((a, b)),
+            Some(_) => Some((b, a)),
+            None => None,
+        }
+    }
+}
+
+/// Finds and resolves the collisions of the objects in the `EntityStore`
 pub struct SysCollision {
-    objects: Vec<Arc<Mutex<dyn Collidable>>>,
+    /// Rebuilt every frame
+    grid: SpatialGrid,
+    /// Rebuilt only when static objects are added or removed
+    static_grid: SpatialGrid,
+    /// Static revision of the store the static grid is built for, `None` if
+    /// it has to be rebuilt
+    static_revision: Option<u64>,
+    /// Broad phase data of the static objects, cached with the static grid
+    static_entries: Vec<BroadPhaseEntry>,
+    pair_filter: Option<PairFilter>,
+    /// Pairs colliding after the last check
+    active_pairs: BTreeMap<PairKey, PairContact>,
     event_bus: Arc<Mutex<SysEvent>>,
 }
 
 impl SysCollision {
+    /// Suits objects of the player size
+    pub const DEFAULT_CELL_SIZE: f32 = 64.0;
+    /// Objects with CCD moving further than this part of their smallest size
+    /// in a tick are swept. Slower ones overlap every wall they cross, so the
+    /// discrete check resolves it to the right side.
+    const CCD_MOTION_FRACTION: f32 = 0.5;
+
     pub fn new(event_bus: Arc<Mutex<SysEvent>>) -> Self {
         Self {
-            objects: Vec::new(),
+            grid: SpatialGrid::new(Self::DEFAULT_CELL_SIZE),
+            static_grid: SpatialGrid::new(Self::DEFAULT_CELL_SIZE),
+            static_revision: None,
+            static_entries: Vec::new(),
+            pair_filter: None,
+            active_pairs: BTreeMap::new(),
             event_bus,
         }
     }
 
-    pub fn add_collidable_object(&mut self, object: Arc<Mutex<dyn Collidable>>) {
-        log::debug!(
-            "Adding collidable object: {:?}",
-            object.lock().unwrap().borrow_base_object().id
+    /// Size of the broad phase grid cells, about the size of the moving
+    /// objects. Panics if it is not a positive number.
+    pub fn set_cell_size(&mut self, cell_size: f32) {
+        self.grid = SpatialGrid::new(cell_size);
+        self.static_grid = SpatialGrid::new(cell_size);
+        self.static_revision = None;
+    }
+
+    pub fn get_cell_size(&self) -> f32 {
+        self.grid.get_cell_size()
+    }
+
+    /// Called for every pair accepted by the collision filters, before the
+    /// narrow phase
+    pub fn set_pair_filter(&mut self, pair_filter: Option<PairFilter>) {
+        self.pair_filter = pair_filter;
+    }
+
+    /// Change the collision filter of an object
+    pub fn set_collision_filter(
+        &mut self,
+        entities: &mut EntityStore,
+        id: EntityId,
+        filter: CollisionFilter,
+    ) {
+        if let Some(object) = entities.get_mut(id) {
+            object.set_collision_filter(filter);
+            // Static objects are cached with the static grid
+            if object.get_body_type() == BodyType::Static {
+                self.static_revision = None;
+            }
+        }
+    }
+
+    /// Index the static objects now instead of on the next collision check,
+    /// e.g. right after a level is loaded
+    pub fn bake_static_objects(&mut self, entities: &EntityStore) {
+        self.static_entries = entities
+            .iter()
+            .filter(|(_, object)| object.get_body_type() == BodyType::Static)
+            .map(|(id, object)| BroadPhaseEntry::new(id, object))
+            .collect();
+        Self::rebuild_grid(&mut self.static_grid, &self.static_entries);
+        self.static_revision = Some(entities.get_static_revision());
+    }
+
+    fn is_static_grid_valid(&self, entities: &EntityStore) -> bool {
+        self.static_revision == Some(entities.get_static_revision())
+    }
+
+    /// Kinematic and dynamic bodies in the order of the store
+    fn get_moving_entries(entities: &EntityStore) -> Vec<BroadPhaseEntry> {
+        entities
+            .iter()
+            .filter(|(_, object)| object.get_body_type() != BodyType::Static)
+            .map(|(id, object)| BroadPhaseEntry::new(id, object))
+            .collect()
+    }
+
+    fn rebuild_grid(grid: &mut SpatialGrid, entries: &[BroadPhaseEntry]) {
+        grid.clear();
+        for (index, entry) in entries.iter().enumerate() {
+            grid.insert(index, &entry.aabb);
+        }
+    }
+
+    /// Resolve the overlap of a pair, let both objects react and note the
+    /// colliding pair. A colliding pair stays colliding while the objects
+    /// touch, so resting against a wall does not restart the collision every
+    /// frame. Objects overlapping a sensor get the sensor callbacks instead
+    /// of `process_collision`.
+    fn collide(
+        entities: &mut EntityStore,
+        pair: (EntityId, EntityId),
+        pair_filter: Option<&PairFilter>,
+        active_pairs: &BTreeMap<PairKey, PairContact>,
+        colliding: &mut BTreeMap<PairKey, PairContact>,
+    ) {
+        let Some((obj, other)) = entities.get_pair_mut(pair.0, pair.1) else {
+            return;
+        };
+        let (id, other_id) = (obj.borrow_base_object().id, other.borrow_base_object().id);
+        let key = (id.min(other_id), id.max(other_id));
+        if colliding.contains_key(&key) {
+            return; // Already handled from the other side
+        }
+        if pair_filter.is_some_and(|accepts| !accepts(obj, other)) {
+            return;
+        }
+        let Some(contact) = obj.get_contact(other) else {
+            return;
+        };
+
+        let was_colliding = active_pairs.contains_key(&key);
+        if contact.dist >= 0.0 && !was_colliding {
+            return;
+        }
+        Self::respond(
+            (obj, other),
+            &contact,
+            was_colliding,
+            contact.dist < 0.0,
+            colliding,
         );
-        self.objects.push(object);
     }
 
-    pub fn add_static_object(&mut self, object: Arc<Mutex<dyn Collidable>>) {
-        self.objects.push(object);
+    /// Let both objects react to the contact and note the colliding pair,
+    /// solid pairs are resolved if `resolve` is set
+    fn respond(
+        objects: (&mut dyn Collidable, &mut dyn Collidable),
+        contact: &Contact,
+        was_colliding: bool,
+        resolve: bool,
+        colliding: &mut BTreeMap<PairKey, PairContact>,
+    ) {
+        let (obj, other) = objects;
+        let (id, other_id) = (obj.borrow_base_object().id, other.borrow_base_object().id);
+        let key = (id.min(other_id), id.max(other_id));
+        let sensor_id = match (obj.is_sensor(), other.is_sensor()) {
+            (true, _) => Some(id),
+            (_, true) => Some(other_id),
+            _ => None,
+        };
+        match (sensor_id, was_colliding) {
+            (None, _) if resolve => {
+                contact_solver::resolve_contact(obj, other, contact);
+                obj.process_collision(other);
+                other.process_collision(obj);
+            }
+            (None, _) => {}
+            (Some(_), false) => {
+                obj.on_sensor_enter(other);
+                other.on_sensor_enter(obj);
+            }
+            (Some(_), true) => {
+                obj.on_sensor_stay(other);
+                other.on_sensor_stay(obj);
+            }
+        }
+
+        let (obj_type, other_type) = (obj.get_collidable_type(), other.get_collidable_type());
+        // The contact normal points from the object to the other one
+        let (normal, type_a, type_b) = if id == key.0 {
+            (contact.normal1, obj_type, other_type)
+        } else {
+            (-contact.normal1, other_type, obj_type)
+        };
+        colliding.insert(
+            key,
+            PairContact {
+                type_a,
+                type_b,
+                normal_xy: (normal.x, normal.y),
+                depth: (-contact.dist).max(0.0),
+                sensor_id,
+            },
+        );
     }
 
-    pub fn cleanup_objects(&mut self) {
-        // Get IDs of objects to remove
-        let mut ids_to_remove = Vec::new();
-        for obj in &self.objects {
-            if obj
-                .lock()
-                .unwrap()
-                .borrow_base_object()
-                .is_deletion_requested()
-            {
-                ids_to_remove.push(obj.lock().unwrap().borrow_base_object().id);
+    /// Objects with CCD moving far enough to skip a wall between two checks
+    fn needs_sweep(object: &dyn Collidable) -> bool {
+        if !object.is_ccd_enabled() || object.get_body_type() != BodyType::Dynamic {
+            return false;
+        }
+        let min_size = object.get_width().min(object.get_height());
+        object.get_motion().norm() > min_size * Self::CCD_MOTION_FRACTION
+    }
+
+    /// Move the fast objects with CCD back to their first impact with a
+    /// static body on the way from the previous position and resolve it, so
+    /// they cannot pass through thin walls. The rest of the movement in this
+    /// tick is dropped.
+    fn sweep_objects(
+        &self,
+        entities: &mut EntityStore,
+        colliding: &mut BTreeMap<PairKey, PairContact>,
+    ) {
+        let pair_filter = self.pair_filter.as_ref();
+        let fast_ids: Vec<EntityId> = entities
+            .iter()
+            .filter(|(_, object)| Self::needs_sweep(*object))
+            .map(|(id, _)| id)
+            .collect();
+        let mut nearby = Vec::new();
+        for id in fast_ids {
+            let Some(obj) = entities.get(id) else {
+                continue;
+            };
+            let entry = BroadPhaseEntry {
+                aabb: obj.get_swept_aabb(),
+                ..BroadPhaseEntry::new(id, obj)
+            };
+            self.static_grid.query(&entry.aabb, &mut nearby);
+
+            // The earliest impact with a solid static body
+            let mut first_impact: Option<(f32, EntityId)> = None;
+            for &j in &nearby {
+                let other_entry = &self.static_entries[j];
+                if other_entry.is_sensor || !entry.accepts(other_entry) {
+                    continue;
+                }
+                let Some(other) = entities.get(other_entry.id) else {
+                    continue;
+                };
+                if pair_filter.is_some_and(|accepts| !accepts(obj, other)) {
+                    continue;
+                }
+                if let Some(time_of_impact) = obj.get_time_of_impact(other) {
+                    if first_impact.is_none_or(|(first, _)| time_of_impact < first) {
+                        first_impact = Some((time_of_impact, other_entry.id));
+                    }
+                }
+            }
+            let Some((time_of_impact, other_id)) = first_impact else {
+                continue;
+            };
+            let Some((obj, other)) = entities.get_pair_mut(id, other_id) else {
+                continue;
+            };
+
+            let rewind = obj.get_motion() * (1.0 - time_of_impact);
+            let base = obj.borrow_mut_base_object();
+            base.x -= rewind.x;
+            base.y -= rewind.y;
+
+            let Some(contact) = obj.get_contact(other) else {
+                continue;
+            };
+            let was_colliding = self
+                .active_pairs
+                .contains_key(&(id.min(other_id), id.max(other_id)));
+            Self::respond((obj, other), &contact, was_colliding, true, colliding);
+        }
+    }
+
+    /// Publish the collisions that started or ended since the last check and
+    /// the sensor events
+    fn publish_collision_events(
+        &mut self,
+        entities: &mut EntityStore,
+        colliding: BTreeMap<PairKey, PairContact>,
+    ) {
+        for (&key, contact) in &colliding {
+            let is_new = !self.active_pairs.contains_key(&key);
+            match contact.get_sensor_pair(key) {
+                Some(((sensor_id, sensor_type), (object_id, object_type))) if is_new => self
+                    .publish(Event::SensorEntered {
+                        sensor_id,
+                        sensor_type,
+                        object_id,
+                        object_type,
+                    }),
+                Some(((sensor_id, sensor_type), (object_id, object_type))) => {
+                    self.publish(Event::SensorStay {
+                        sensor_id,
+                        sensor_type,
+                        object_id,
+                        object_type,
+                    })
+                }
+                None if is_new => self.publish(Event::CollisionStarted {
+                    id_a: key.0,
+                    id_b: key.1,
+                    type_a: contact.type_a,
+                    type_b: contact.type_b,
+                    normal_xy: contact.normal_xy,
+                    depth: contact.depth,
+                }),
+                None => {}
+            }
+        }
+
+        let active_pairs = std::mem::replace(&mut self.active_pairs, colliding);
+        let ended = active_pairs
+            .into_iter()
+            .filter(|(key, _)| !self.active_pairs.contains_key(key))
+            .collect();
+        self.end_pairs(entities, ended);
+    }
+
+    /// Publish `SensorExited` or `CollisionEnded` for the pairs that are not
+    /// active anymore
+    fn end_pairs(&mut self, entities: &mut EntityStore, ended: Vec<(PairKey, PairContact)>) {
+        for (key, contact) in ended {
+            match contact.get_sensor_pair(key) {
+                Some(((sensor_id, sensor_type), (object_id, object_type))) => {
+                    Self::call_sensor_exit(entities, key, &contact);
+                    self.publish(Event::SensorExited {
+                        sensor_id,
+                        sensor_type,
+                        object_id,
+                        object_type,
+                    });
+                }
+                None => self.publish(Event::CollisionEnded {
+                    id_a: key.0,
+                    id_b: key.1,
+                    type_a: contact.type_a,
+                    type_b: contact.type_b,
+                }),
+            }
+        }
+    }
+
+    /// Forget the despawned entities. Their pairs end right away, so the
+    /// remaining objects get `on_sensor_exit` and the end events are
+    /// published before the ids are reused.
+    pub fn remove_entities(&mut self, entities: &mut EntityStore, ids: &[EntityId]) {
+        let keys: Vec<PairKey> = self
+            .active_pairs
+            .keys()
+            .filter(|key| ids.contains(&key.0) || ids.contains(&key.1))
+            .copied()
+            .collect();
+        let ended = keys
+            .into_iter()
+            .filter_map(|key| Some((key, self.active_pairs.remove(&key)?)))
+            .collect();
+        self.end_pairs(entities, ended);
+    }
+
+    /// Tell both objects of the pair, unless they were removed
+    fn call_sensor_exit(entities: &mut EntityStore, key: PairKey, contact: &PairContact) {
+        for (id, other_id, other_type) in [
+            (key.0, key.1, contact.type_b),
+            (key.1, key.0, contact.type_a),
+        ] {
+            if let Some(object) = entities.get_mut(id) {
+                object.on_sensor_exit(other_id, other_type);
             }
         }
+    }
+
+    /// Ids of the objects overlapping the sensor after the last check
+    pub fn get_sensor_contacts(&self, sensor_id: EntityId) -> Vec<EntityId> {
+        self.active_pairs
+            .iter()
+            .filter_map(|(&key, contact)| match contact.get_sensor_pair(key) {
+                Some(((id, _), (object_id, _))) if id == sensor_id => Some(object_id),
+                _ => None,
+            })
+            .collect()
+    }
+
+    /// Objects accepted by the filter with the bounding box intersecting the
+    /// area. Static objects come from the grid unless it is outdated, the
+    /// moving ones are checked directly as the grid is rebuilt only by
+    /// `process_collisions`.
+    fn get_candidates(
+        &self,
+        entities: &EntityStore,
+        aabb: &Aabb,
+        filter: &QueryFilter,
+    ) -> Vec<EntityId> {
+        let mut candidates = Vec::new();
+        if self.is_static_grid_valid(entities) {
+            let mut nearby = Vec::new();
+            self.static_grid.query(aabb, &mut nearby);
+            candidates.extend(nearby.iter().map(|&j| self.static_entries[j].id));
+            candidates.extend(
+                entities
+                    .iter()
+                    .filter(|(_, object)| object.get_body_type() != BodyType::Static)
+                    .map(|(id, _)| id),
+            );
+        } else {
+            candidates.extend(entities.iter().map(|(id, _)| id));
+        }
+        candidates.retain(|&id| {
+            entities
+                .get(id)
+                .is_some_and(|object| filter.accepts(object) && object.get_aabb().intersects(aabb))
+        });
+        candidates
+    }
+
+    /// Ids of the objects containing the point, e.g. for the mouse picking
+    pub fn query_point(
+        &self,
+        entities: &EntityStore,
+        point_xy: (f32, f32),
+        filter: &QueryFilter,
+    ) -> Vec<EntityId> {
+        let point = Point::new(point_xy.0, point_xy.1);
+        self.get_candidates(entities, &Aabb::new(point, point), filter)
+            .into_iter()
+            .filter(|&id| {
+                entities.get(id).is_some_and(|object| {
+                    let shape = object.borrow_collidable_attributes().get_parry_shape();
+                    shape.contains_point(&object.get_isometry(), &point)
+                })
+            })
+            .collect()
+    }
+
+    /// Ids of the objects closer than the radius to the centre, the nearest
+    /// first, e.g. to check if a spawn point is free
+    pub fn query_radius(
+        &self,
+        entities: &EntityStore,
+        center_xy: (f32, f32),
+        radius: f32,
+        filter: &QueryFilter,
+    ) -> Vec<EntityId> {
+        let center = Point::new(center_xy.0, center_xy.1);
+        let area = Aabb::from_half_extents(center, Vector::repeat(radius));
+        let mut found: Vec<(f32, EntityId)> = self
+            .get_candidates(entities, &area, filter)
+            .into_iter()
+            .filter_map(|id| {
+                let object = entities.get(id)?;
+                let shape = object.borrow_collidable_attributes().get_parry_shape();
+                let distance = shape.distance_to_point(&object.get_isometry(), &center, true);
+                (distance <= radius).then_some((distance, id))
+            })
+            .collect();
+        found.sort_by(|a, b| a.0.total_cmp(&b.0).then(a.1.cmp(&b.1)));
+        found.into_iter().map(|(_, id)| id).collect()
+    }
+
+    /// The closest object hit by the ray within the distance, e.g. for the
+    /// line of sight. A ray starting inside an object hits it at the origin.
+    pub fn cast_ray(
+        &self,
+        entities: &EntityStore,
+        origin_xy: (f32, f32),
+        direction_xy: (f32, f32),
+        max_distance: f32,
+        filter: &QueryFilter,
+    ) -> Option<RayHit> {
+        let direction = Vector::new(direction_xy.0, direction_xy.1).try_normalize(f32::EPSILON)?;
+        let ray = Ray::new(Point::new(origin_xy.0, origin_xy.1), direction);
+        // An axis the ray does not move along keeps the origin, as
+        // 0 * infinity is NaN
+        let reach = |origin: f32, direction: f32| {
+            if direction == 0.0 {
+                origin
+            } else {
+                origin + direction * max_distance
+            }
+        };
+        let end = Point::new(
+            reach(ray.origin.x, direction.x),
+            reach(ray.origin.y, direction.y),
+        );
+        let area = Aabb::from_points(&[ray.origin, end]);
 
-        // Remove objects from the list
-        self.objects
-            .retain(|obj| !ids_to_remove.contains(&obj.lock().unwrap().borrow_base_object().id));
+        let mut closest: Option<RayHit> = None;
+        for id in self.get_candidates(entities, &area, filter) {
+            let Some(object) = entities.get(id) else {
+                continue;
+            };
+            let shape = object.borrow_collidable_attributes().get_parry_shape();
+            let Some(hit) =
+                shape.cast_ray_and_get_normal(&object.get_isometry(), &ray, max_distance, true)
+            else {
+                continue;
+            };
+            if closest.is_some_and(|closest| closest.distance <= hit.time_of_impact) {
+                continue;
+            }
+            let point = ray.point_at(hit.time_of_impact);
+            closest = Some(RayHit {
+                object_id: id,
+                object_type: object.get_collidable_type(),
+                point_xy: (point.x, point.y),
+                normal_xy: (hit.normal.x, hit.normal.y),
+                distance: hit.time_of_impact,
+            });
+        }
+        closest
     }
 
-    pub fn process_collisions(&self) {
-        // Create a vector of indices to sort
-        let mut indices: Vec<usize> = (0..self.objects.len()).collect();
-        indices.sort_by_key(|&i| self.objects[i].lock().unwrap().borrow_base_object().id);
+    /// Only the pairs in the same grid cells with at least one dynamic body,
+    /// accepted by the collision filters and the pair filter reach the narrow
+    /// phase. Fast objects with CCD are swept against the static objects
+    /// first. Every moving object is checked against the nearby moving
+    /// objects, dynamic ones also against the nearby static objects, every
+    /// pair is handled once. `CollisionStarted` and `CollisionEnded` are
+    /// published for every pair once.
+    pub fn process_collisions(&mut self, entities: &mut EntityStore) {
+        if !self.is_static_grid_valid(entities) {
+            self.bake_static_objects(entities);
+        }
+        let mut colliding = BTreeMap::new();
+        self.sweep_objects(entities, &mut colliding);
+
+        let entries = Self::get_moving_entries(entities);
+        Self::rebuild_grid(&mut self.grid, &entries);
 
-        for &i in &indices {
-            let mut obj = self.objects[i].lock().unwrap();
-            for &j in &indices {
-                if i == j {
-                    continue; // Skip self-collision
+        let pair_filter = self.pair_filter.as_ref();
+        let mut nearby = Vec::new();
+        for (i, entry) in entries.iter().enumerate() {
+            self.grid.query(&entry.aabb, &mut nearby);
+            for &j in &nearby {
+                if j != i && entry.accepts(&entries[j]) {
+                    Self::collide(
+                        entities,
+                        (entry.id, entries[j].id),
+                        pair_filter,
+                        &self.active_pairs,
+                        &mut colliding,
+                    );
+                }
+            }
+            if entry.body_type != BodyType::Dynamic {
+                continue;
+            }
+            self.static_grid.query(&entry.aabb, &mut nearby);
+            for &j in &nearby {
+                if entry.accepts(&self.static_entries[j]) {
+                    Self::collide(
+                        entities,
+                        (entry.id, self.static_entries[j].id),
+                        pair_filter,
+                        &self.active_pairs,
+                        &mut colliding,
+                    );
                 }
-                let other = self.objects[j].lock().unwrap();
-                obj.try_collide(&*other);
             }
         }
+
+        self.publish_collision_events(entities, colliding);
+    }
+}
+
+impl Publisher for SysCollision {
+    fn get_event_bus(&self) -> Arc<Mutex<SysEvent>> {
+        self.event_bus.clone()
     }
 }
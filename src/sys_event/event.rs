@@ -9,13 +9,67 @@
 // e-mail:  mail@agramakov.me
 //
 // *************************************************************************
+use crate::{entity::EntityId, objects::PlayerMode, sys_collision::CollidableType};
 use macroquad::color::Color;
-use crate::objects::PlayerMode;
 
-#[derive(Debug)]
+/// Events of the crate, games publish their own event types next to them
+#[derive(Debug, Clone)]
 pub enum Event {
-    MovePlayer { id: u32, fx: f32, fy: f32 },
-    SetPlayerMode { id: u32, mode: PlayerMode },
-    ChangeColor { id: u32, color: Option<Color> },
+    MovePlayer {
+        id: u32,
+        fx: f32,
+        fy: f32,
+    },
+    SetPlayerMode {
+        id: u32,
+        mode: PlayerMode,
+    },
+    ChangeColor {
+        id: u32,
+        color: Option<Color>,
+    },
+    /// Two objects started to overlap. `id_a` is the lower object id, the
+    /// types are the collidable types, the normal points from `a` to `b`.
+    CollisionStarted {
+        id_a: EntityId,
+        id_b: EntityId,
+        type_a: CollidableType,
+        type_b: CollidableType,
+        normal_xy: (f32, f32),
+        depth: f32,
+    },
+    /// Two objects are apart again or one of them was deleted
+    CollisionEnded {
+        id_a: EntityId,
+        id_b: EntityId,
+        type_a: CollidableType,
+        type_b: CollidableType,
+    },
+    /// An object started to overlap a sensor
+    SensorEntered {
+        sensor_id: EntityId,
+        sensor_type: CollidableType,
+        object_id: EntityId,
+        object_type: CollidableType,
+    },
+    /// Published on every check while the object overlaps the sensor
+    SensorStay {
+        sensor_id: EntityId,
+        sensor_type: CollidableType,
+        object_id: EntityId,
+        object_type: CollidableType,
+    },
+    /// The object left the sensor or one of them was deleted
+    SensorExited {
+        sensor_id: EntityId,
+        sensor_type: CollidableType,
+        object_id: EntityId,
+        object_type: CollidableType,
+    },
+    /// The entity was removed from the world, its id is not valid anymore
+    EntityDespawned {
+        id: EntityId,
+        object_type: CollidableType,
+    },
     Quit,
 }
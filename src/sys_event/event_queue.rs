@@ -9,14 +9,13 @@
 // e-mail:  mail@agramakov.me
 //
 // *************************************************************************
-use super::Event;
 use std::collections::VecDeque;
 
-pub struct EventQueue {
-    events: VecDeque<Event>,
+pub struct EventQueue<T> {
+    events: VecDeque<T>,
 }
 
-impl EventQueue {
+impl<T> EventQueue<T> {
     pub fn new(max_size: usize) -> Self {
         Self {
             events: VecDeque::with_capacity(max_size),
@@ -24,12 +23,12 @@ impl EventQueue {
     }
 
     // Push a new event into the queue
-    pub fn push(&mut self, event: Event) {
+    pub fn push(&mut self, event: T) {
         self.events.push_back(event);
     }
 
     // Pop an event from the queue
-    pub fn pop(&mut self) -> Option<Event> {
+    pub fn pop(&mut self) -> Option<T> {
         self.events.pop_front()
     }
 }
@@ -0,0 +1,39 @@
+// *************************************************************************
+//
+// Copyright (c) 2025 Andrei Gramakov. All rights reserved.
+//
+// This file is licensed under the terms of the MIT license.
+// For a copy, see: https://opensource.org/licenses/MIT
+//
+// site:    https://agramakov.me
+// e-mail:  mail@agramakov.me
+//
+// *************************************************************************
+use std::{
+    any::Any,
+    sync::{Arc, Mutex},
+};
+
+/// Publishes to the bus without locking it, so the handlers and subscribers
+/// can publish follow-up events while the bus processes. The events are
+/// processed on the next tick, after the ones already queued.
+#[derive(Clone)]
+pub struct EventSender {
+    pending: Arc<Mutex<Vec<Box<dyn Any + Send>>>>,
+}
+
+impl EventSender {
+    pub(super) fn new() -> Self {
+        Self {
+            pending: Arc::new(Mutex::new(Vec::new())),
+        }
+    }
+
+    pub fn publish<E: Any + Send>(&self, event: E) {
+        self.pending.lock().unwrap().push(Box::new(event));
+    }
+
+    pub(super) fn take_pending(&self) -> Vec<Box<dyn Any + Send>> {
+        std::mem::take(&mut self.pending.lock().unwrap())
+    }
+}
@@ -11,10 +11,15 @@
 // *************************************************************************
 mod event;
 mod event_queue;
+mod event_sender;
 mod publisher;
 mod subscriber;
+mod subscription_id;
+#[allow(clippy::module_inception)]
 mod sys_event;
 pub use event::Event;
+pub use event_sender::EventSender;
 pub use publisher::Publisher;
 pub use subscriber::Subscriber;
+pub use subscription_id::SubscriptionId;
 pub use sys_event::SysEvent;
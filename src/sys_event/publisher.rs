@@ -9,13 +9,15 @@
 // e-mail:  mail@agramakov.me
 //
 // *************************************************************************
-use super::event::Event;
 use super::sys_event::SysEvent;
-use std::sync::{Arc, Mutex};
+use std::{
+    any::Any,
+    sync::{Arc, Mutex},
+};
 
 pub trait Publisher {
     fn get_event_bus(&self) -> Arc<Mutex<SysEvent>>;
-    fn publish(&self, event: Event) {
+    fn publish<E: Any + Send>(&self, event: E) {
         let bus = self.get_event_bus();
         bus.lock().unwrap().publish(event);
     }
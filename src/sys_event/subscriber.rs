@@ -12,8 +12,13 @@
 use super::Event;
 use std::fmt;
 
-pub trait Subscriber {
+pub trait Subscriber: Send {
     fn handle_busevent(&mut self, event: &Event);
+
+    /// Subscribers returning `false` are dropped on the next cleanup
+    fn is_subscribed(&self) -> bool {
+        true
+    }
 }
 
 impl fmt::Debug for dyn Subscriber {
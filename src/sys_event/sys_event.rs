@@ -10,45 +10,148 @@
 //
 // *************************************************************************
 
-use super::{event_queue::EventQueue, Event, Subscriber};
-use log::*;
-use std::sync::{Arc, Mutex};
+use super::{event_queue::EventQueue, Event, EventSender, Subscriber, SubscriptionId};
+use crate::entity::EntityStore;
+use std::{
+    any::{Any, TypeId},
+    collections::HashMap,
+    sync::{Arc, Mutex},
+};
+
+type Handler = Box<dyn FnMut(&dyn Any) + Send>;
 
 pub struct SysEvent {
-    queue: EventQueue,
-    /// Thread safe list of subscribers
-    subscribers: Arc<Mutex<Vec<Arc<Mutex<dyn Subscriber>>>>>,
+    /// Events of any type, in the order of publishing
+    queue: EventQueue<Box<dyn Any + Send>>,
+    /// Events published with the sender, moved to the queue while processing
+    sender: EventSender,
+    /// Subscribers of `Event`, kept to drop their handlers on cleanup
+    subscribers: Vec<(SubscriptionId, Arc<Mutex<dyn Subscriber>>)>,
+    /// Handlers by event type, in the order of subscribing
+    handlers: HashMap<TypeId, Vec<(SubscriptionId, Handler)>>,
+    next_subscription_id: u64,
+}
+
+impl Default for SysEvent {
+    fn default() -> Self {
+        Self::new()
+    }
 }
 
 impl SysEvent {
     pub fn new() -> Self {
         Self {
             queue: EventQueue::new(100),
-            subscribers: Arc::new(Mutex::new(Vec::new())),
+            sender: EventSender::new(),
+            subscribers: Vec::new(),
+            handlers: HashMap::new(),
+            next_subscription_id: 0,
+        }
+    }
+
+    /// Receive every `Event`, the subscriber is a handler of the `Event`
+    /// type. It is dropped on cleanup once it is not subscribed anymore.
+    pub fn subscribe(&mut self, subscriber: Arc<Mutex<dyn Subscriber>>) -> SubscriptionId {
+        let handled = subscriber.clone();
+        let id = self.subscribe_to(move |event: &Event| {
+            handled.lock().unwrap().handle_busevent(event);
+        });
+        self.subscribers.push((id, subscriber));
+        id
+    }
+
+    /// Call the handler for every event of the type `E`, which can be `Event`
+    /// or any type defined by the game. The handler runs while the bus is
+    /// locked, follow-up events are published with `get_sender`.
+    pub fn subscribe_to<E: Any, F: FnMut(&E) + Send + 'static>(
+        &mut self,
+        mut handler: F,
+    ) -> SubscriptionId {
+        let id = SubscriptionId::new(self.next_subscription_id);
+        self.next_subscription_id += 1;
+        let handler: Handler = Box::new(move |event: &dyn Any| {
+            if let Some(event) = event.downcast_ref::<E>() {
+                handler(event);
+            }
+        });
+        self.handlers
+            .entry(TypeId::of::<E>())
+            .or_default()
+            .push((id, handler));
+        id
+    }
+
+    /// `false` if the handler is already unsubscribed
+    pub fn unsubscribe(&mut self, id: SubscriptionId) -> bool {
+        self.subscribers
+            .retain(|(subscription_id, _)| *subscription_id != id);
+        for handlers in self.handlers.values_mut() {
+            let count = handlers.len();
+            handlers.retain(|(handler_id, _)| *handler_id != id);
+            if handlers.len() != count {
+                return true;
+            }
         }
+        false
     }
 
-    // Use a shared reference (`&self`), since we're only modifying the inner `Vec`
-    pub fn subscribe(&self, subscriber: Arc<Mutex<dyn Subscriber>>) {
-        self.subscribers.lock().unwrap().push(subscriber);
+    /// Queue an event for the next `process_all`. Only `Event` reaches the
+    /// entities, every type goes to its handlers.
+    pub fn publish<E: Any + Send>(&mut self, event: E) {
+        self.queue.push(Box::new(event));
     }
 
-    // Method to publish an event to all subscribers
-    pub fn publish(&mut self, event: Event) {
-        self.queue.push(event);
+    /// Publishes without locking the bus, e.g. from a handler
+    pub fn get_sender(&self) -> EventSender {
+        self.sender.clone()
     }
 
-    // Method to process all events in the queue
-    pub fn process_all(&mut self) {
+    /// Hand every event in the queue to the entities that subscribe to
+    /// events and to the handlers of its type. Events published with the
+    /// sender meanwhile wait for the next call, so a handler publishing its
+    /// own event type cannot keep the bus busy forever.
+    pub fn process_all(&mut self, entities: &mut EntityStore) {
+        self.queue_sent();
         while let Some(event) = self.queue.pop() {
-            self.process(event);
+            self.process(event, entities);
+        }
+    }
+
+    /// Drop the subscribers that are not interested anymore, e.g. deleted objects
+    pub fn cleanup_subscribers(&mut self) {
+        let finished: Vec<SubscriptionId> = self
+            .subscribers
+            .iter()
+            .filter(|(_, subscriber)| !subscriber.lock().unwrap().is_subscribed())
+            .map(|(id, _)| *id)
+            .collect();
+        for id in finished {
+            self.unsubscribe(id);
+        }
+    }
+
+    fn queue_sent(&mut self) {
+        for event in self.sender.take_pending() {
+            self.queue.push(event);
+        }
+    }
+
+    fn process(&mut self, event: Box<dyn Any + Send>, entities: &mut EntityStore) {
+        if let Some(event) = event.downcast_ref::<Event>() {
+            self.process_event(event, entities);
+        }
+        if let Some(handlers) = self.handlers.get_mut(&(*event).type_id()) {
+            for (_, handler) in handlers.iter_mut() {
+                handler(event.as_ref());
+            }
         }
     }
 
-    fn process(&self, event: Event) {
-        let mut subscribers = self.subscribers.lock().unwrap();
-        for subscriber in subscribers.iter_mut() {
-            subscriber.lock().unwrap().handle_busevent(&event);
+    fn process_event(&mut self, event: &Event, entities: &mut EntityStore) {
+        for (_, entity) in entities.iter_mut() {
+            if let Some(subscriber) = entity.as_subscriber_mut() {
+                subscriber.handle_busevent(event);
+            }
         }
     }
 }
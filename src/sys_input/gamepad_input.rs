@@ -0,0 +1,57 @@
+// *************************************************************************
+//
+// Copyright (c) 2025 Andrei Gramakov. All rights reserved.
+//
+// This file is licensed under the terms of the MIT license.
+// For a copy, see: https://opensource.org/licenses/MIT
+//
+// site:    https://agramakov.me
+// e-mail:  mail@agramakov.me
+//
+// *************************************************************************
+use super::{GamepadAxis, InputSource, InputState};
+use gamepads::{Button, Gamepads};
+
+/// Connected gamepads, identified by `GamepadId::value`
+pub struct GamepadInput {
+    gamepads: Gamepads,
+}
+
+impl Default for GamepadInput {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl GamepadInput {
+    pub fn new() -> Self {
+        Self {
+            gamepads: Gamepads::new(),
+        }
+    }
+}
+
+impl InputSource for GamepadInput {
+    fn poll(&mut self, state: &mut InputState) {
+        self.gamepads.poll();
+        for gamepad in self.gamepads.all() {
+            let gamepad_state = state.get_gamepad_mut(gamepad.id().value());
+            for button in Button::all() {
+                if gamepad.is_currently_pressed(button) {
+                    gamepad_state.press_button(button);
+                }
+            }
+            for axis in GamepadAxis::ALL {
+                let value = match axis {
+                    GamepadAxis::LeftStickX => gamepad.left_stick_x(),
+                    GamepadAxis::LeftStickY => gamepad.left_stick_y(),
+                    GamepadAxis::RightStickX => gamepad.right_stick_x(),
+                    GamepadAxis::RightStickY => gamepad.right_stick_y(),
+                    GamepadAxis::LeftTrigger => gamepad.left_trigger(),
+                    GamepadAxis::RightTrigger => gamepad.right_trigger(),
+                };
+                gamepad_state.set_axis(axis, value);
+            }
+        }
+    }
+}
@@ -0,0 +1,247 @@
+// *************************************************************************
+//
+// Copyright (c) 2025 Andrei Gramakov. All rights reserved.
+//
+// This file is licensed under the terms of the MIT license.
+// For a copy, see: https://opensource.org/licenses/MIT
+//
+// site:    https://agramakov.me
+// e-mail:  mail@agramakov.me
+//
+// *************************************************************************
+use gamepads::Button;
+use macroquad::input::KeyCode;
+use std::fmt;
+
+/// Analog gamepad input, the values are in the range [-1.0, 1.0]
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum GamepadAxis {
+    LeftStickX,
+    LeftStickY,
+    RightStickX,
+    RightStickY,
+    LeftTrigger,
+    RightTrigger,
+}
+
+impl GamepadAxis {
+    pub const ALL: [GamepadAxis; 6] = [
+        GamepadAxis::LeftStickX,
+        GamepadAxis::LeftStickY,
+        GamepadAxis::RightStickX,
+        GamepadAxis::RightStickY,
+        GamepadAxis::LeftTrigger,
+        GamepadAxis::RightTrigger,
+    ];
+}
+
+/// A physical input that triggers an `Action`
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum InputBinding {
+    Key(KeyCode),
+    GamepadButton(Button),
+    /// Half of an axis: `positive` selects the direction that activates the action
+    GamepadAxis {
+        axis: GamepadAxis,
+        positive: bool,
+    },
+}
+
+impl InputBinding {
+    /// Parse the config notation: `key:Up`, `button:ActionDown`, `axis:LeftStickX+`
+    pub fn parse(text: &str) -> Result<InputBinding, String> {
+        let (kind, name) = text
+            .split_once(':')
+            .ok_or_else(|| format!("binding '{}' has no type prefix", text))?;
+        match kind {
+            "key" => parse_key(name).map(InputBinding::Key),
+            "button" => parse_button(name).map(InputBinding::GamepadButton),
+            "axis" => {
+                let (axis_name, positive) = if let Some(axis) = name.strip_suffix('+') {
+                    (axis, true)
+                } else if let Some(axis) = name.strip_suffix('-') {
+                    (axis, false)
+                } else {
+                    return Err(format!("axis '{}' has no direction (+ or -)", name));
+                };
+                parse_axis(axis_name).map(|axis| InputBinding::GamepadAxis { axis, positive })
+            }
+            _ => Err(format!("unknown binding type '{}'", kind)),
+        }
+    }
+}
+
+impl fmt::Display for InputBinding {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self {
+            InputBinding::Key(key) => write!(f, "key:{:?}", key),
+            InputBinding::GamepadButton(button) => write!(f, "button:{:?}", button),
+            InputBinding::GamepadAxis { axis, positive } => {
+                write!(f, "axis:{:?}{}", axis, if *positive { '+' } else { '-' })
+            }
+        }
+    }
+}
+
+pub(crate) fn parse_key(name: &str) -> Result<KeyCode, String> {
+    KEY_CODES
+        .into_iter()
+        .find(|key| format!("{:?}", key) == name)
+        .ok_or_else(|| format!("unknown key '{}'", name))
+}
+
+pub(crate) fn parse_button(name: &str) -> Result<Button, String> {
+    Button::all()
+        .find(|button| format!("{:?}", button) == name)
+        .ok_or_else(|| format!("unknown gamepad button '{}'", name))
+}
+
+pub(crate) fn parse_axis(name: &str) -> Result<GamepadAxis, String> {
+    GamepadAxis::ALL
+        .into_iter()
+        .find(|axis| format!("{:?}", axis) == name)
+        .ok_or_else(|| format!("unknown gamepad axis '{}'", name))
+}
+
+/// Every key known to macroquad, used to parse the key names
+const KEY_CODES: [KeyCode; 122] = [
+    KeyCode::Space,
+    KeyCode::Apostrophe,
+    KeyCode::Comma,
+    KeyCode::Minus,
+    KeyCode::Period,
+    KeyCode::Slash,
+    KeyCode::Key0,
+    KeyCode::Key1,
+    KeyCode::Key2,
+    KeyCode::Key3,
+    KeyCode::Key4,
+    KeyCode::Key5,
+    KeyCode::Key6,
+    KeyCode::Key7,
+    KeyCode::Key8,
+    KeyCode::Key9,
+    KeyCode::Semicolon,
+    KeyCode::Equal,
+    KeyCode::A,
+    KeyCode::B,
+    KeyCode::C,
+    KeyCode::D,
+    KeyCode::E,
+    KeyCode::F,
+    KeyCode::G,
+    KeyCode::H,
+    KeyCode::I,
+    KeyCode::J,
+    KeyCode::K,
+    KeyCode::L,
+    KeyCode::M,
+    KeyCode::N,
+    KeyCode::O,
+    KeyCode::P,
+    KeyCode::Q,
+    KeyCode::R,
+    KeyCode::S,
+    KeyCode::T,
+    KeyCode::U,
+    KeyCode::V,
+    KeyCode::W,
+    KeyCode::X,
+    KeyCode::Y,
+    KeyCode::Z,
+    KeyCode::LeftBracket,
+    KeyCode::Backslash,
+    KeyCode::RightBracket,
+    KeyCode::GraveAccent,
+    KeyCode::World1,
+    KeyCode::World2,
+    KeyCode::Escape,
+    KeyCode::Enter,
+    KeyCode::Tab,
+    KeyCode::Backspace,
+    KeyCode::Insert,
+    KeyCode::Delete,
+    KeyCode::Right,
+    KeyCode::Left,
+    KeyCode::Down,
+    KeyCode::Up,
+    KeyCode::PageUp,
+    KeyCode::PageDown,
+    KeyCode::Home,
+    KeyCode::End,
+    KeyCode::CapsLock,
+    KeyCode::ScrollLock,
+    KeyCode::NumLock,
+    KeyCode::PrintScreen,
+    KeyCode::Pause,
+    KeyCode::F1,
+    KeyCode::F2,
+    KeyCode::F3,
+    KeyCode::F4,
+    KeyCode::F5,
+    KeyCode::F6,
+    KeyCode::F7,
+    KeyCode::F8,
+    KeyCode::F9,
+    KeyCode::F10,
+    KeyCode::F11,
+    KeyCode::F12,
+    KeyCode::F13,
+    KeyCode::F14,
+    KeyCode::F15,
+    KeyCode::F16,
+    KeyCode::F17,
+    KeyCode::F18,
+    KeyCode::F19,
+    KeyCode::F20,
+    KeyCode::F21,
+    KeyCode::F22,
+    KeyCode::F23,
+    KeyCode::F24,
+    KeyCode::F25,
+    KeyCode::Kp0,
+    KeyCode::Kp1,
+    KeyCode::Kp2,
+    KeyCode::Kp3,
+    KeyCode::Kp4,
+    KeyCode::Kp5,
+    KeyCode::Kp6,
+    KeyCode::Kp7,
+    KeyCode::Kp8,
+    KeyCode::Kp9,
+    KeyCode::KpDecimal,
+    KeyCode::KpDivide,
+    KeyCode::KpMultiply,
+    KeyCode::KpSubtract,
+    KeyCode::KpAdd,
+    KeyCode::KpEnter,
+    KeyCode::KpEqual,
+    KeyCode::LeftShift,
+    KeyCode::LeftControl,
+    KeyCode::LeftAlt,
+    KeyCode::LeftSuper,
+    KeyCode::RightShift,
+    KeyCode::RightControl,
+    KeyCode::RightAlt,
+    KeyCode::RightSuper,
+    KeyCode::Menu,
+    KeyCode::Back,
+    KeyCode::Unknown,
+];
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn every_binding_name_parses_back() {
+        let keys = KEY_CODES.into_iter().map(InputBinding::Key);
+        let buttons = Button::all().map(InputBinding::GamepadButton);
+        let axes = GamepadAxis::ALL.into_iter().flat_map(|axis| {
+            [true, false].map(|positive| InputBinding::GamepadAxis { axis, positive })
+        });
+        for binding in keys.chain(buttons).chain(axes) {
+            assert_eq!(InputBinding::parse(&binding.to_string()), Ok(binding));
+        }
+    }
+}
@@ -0,0 +1,208 @@
+// *************************************************************************
+//
+// Copyright (c) 2025 Andrei Gramakov. All rights reserved.
+//
+// This file is licensed under the terms of the MIT license.
+// For a copy, see: https://opensource.org/licenses/MIT
+//
+// site:    https://agramakov.me
+// e-mail:  mail@agramakov.me
+//
+// *************************************************************************
+use super::{Action, GamepadAxis, InputBinding};
+use crate::base::FileError;
+use gamepads::Button;
+use macroquad::input::KeyCode;
+use std::{collections::BTreeMap, fs, io, path::Path};
+
+/// Bindings of the input devices to the actions of every player.
+///
+/// The config format is line based, `#` starts a comment:
+/// ```text
+/// deadzone 0.1
+/// player 0 move_up key:Up axis:LeftStickY+
+/// player 0 no_clip key:RightControl button:ActionDown
+/// ```
+/// Gamepad bindings of a player apply to the gamepad assigned to the player.
+#[derive(Debug, Clone)]
+pub struct InputMap {
+    deadzone: f32,
+    bindings: BTreeMap<u32, BTreeMap<Action, Vec<InputBinding>>>,
+}
+
+impl Default for InputMap {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl InputMap {
+    pub const DEFAULT_DEADZONE: f32 = 0.1;
+
+    /// Map without any bindings
+    pub fn new() -> Self {
+        Self {
+            deadzone: InputMap::DEFAULT_DEADZONE,
+            bindings: BTreeMap::new(),
+        }
+    }
+
+    pub fn parse(text: &str) -> Result<Self, FileError> {
+        let mut map = InputMap::new();
+        for (index, line) in text.lines().enumerate() {
+            let error = |message: String| FileError::Parse {
+                line: index + 1,
+                message,
+            };
+            let line = line.split('#').next().unwrap_or_default();
+            let mut words = line.split_whitespace();
+            match words.next() {
+                None => continue,
+                Some("deadzone") => {
+                    let value = words
+                        .next()
+                        .ok_or_else(|| error("no deadzone value".into()))?;
+                    map.deadzone = value
+                        .parse()
+                        .ok()
+                        .filter(|deadzone| (0.0..1.0).contains(deadzone))
+                        .ok_or_else(|| error(format!("invalid deadzone '{}'", value)))?;
+                }
+                Some("player") => {
+                    let id = words.next().ok_or_else(|| error("no player id".into()))?;
+                    let player_id = id
+                        .parse()
+                        .map_err(|_| error(format!("invalid player id '{}'", id)))?;
+                    let name = words.next().ok_or_else(|| error("no action".into()))?;
+                    let action = Action::from_name(name)
+                        .ok_or_else(|| error(format!("unknown action '{}'", name)))?;
+                    for binding in words {
+                        map.bind(
+                            player_id,
+                            action,
+                            InputBinding::parse(binding).map_err(error)?,
+                        );
+                    }
+                }
+                Some(other) => return Err(error(format!("unknown entry '{}'", other))),
+            }
+        }
+        Ok(map)
+    }
+
+    pub fn load<P: AsRef<Path>>(path: P) -> Result<Self, FileError> {
+        InputMap::parse(&fs::read_to_string(path)?)
+    }
+
+    pub fn save<P: AsRef<Path>>(&self, path: P) -> io::Result<()> {
+        fs::write(path, self.to_config_string())
+    }
+
+    pub fn to_config_string(&self) -> String {
+        let mut text = format!("deadzone {}\n", self.deadzone);
+        for (player_id, actions) in &self.bindings {
+            for (action, bindings) in actions {
+                let bindings: Vec<String> = bindings.iter().map(|b| b.to_string()).collect();
+                text += &format!("player {} {} {}\n", player_id, action, bindings.join(" "));
+            }
+        }
+        text
+    }
+
+    /// Keyboard layout for the player registered `slot_index`-th, arrows
+    /// for the first one and WASD for the second one. The gamepad stick and
+    /// the A button are bound for everyone.
+    pub fn add_default_bindings(&mut self, player_id: u32, slot_index: usize) {
+        let keys = match slot_index {
+            0 => Some([
+                KeyCode::Up,
+                KeyCode::Down,
+                KeyCode::Left,
+                KeyCode::Right,
+                KeyCode::RightControl,
+            ]),
+            1 => Some([
+                KeyCode::W,
+                KeyCode::S,
+                KeyCode::A,
+                KeyCode::D,
+                KeyCode::LeftShift,
+            ]),
+            _ => None,
+        };
+        if let Some(keys) = keys {
+            for (action, key) in Action::ALL.into_iter().zip(keys) {
+                self.bind(player_id, action, InputBinding::Key(key));
+            }
+        }
+
+        let stick = [
+            (Action::MoveUp, GamepadAxis::LeftStickY, true),
+            (Action::MoveDown, GamepadAxis::LeftStickY, false),
+            (Action::MoveLeft, GamepadAxis::LeftStickX, false),
+            (Action::MoveRight, GamepadAxis::LeftStickX, true),
+        ];
+        for (action, axis, positive) in stick {
+            self.bind(
+                player_id,
+                action,
+                InputBinding::GamepadAxis { axis, positive },
+            );
+        }
+        self.bind(
+            player_id,
+            Action::NoClip,
+            InputBinding::GamepadButton(Button::ActionDown),
+        );
+    }
+
+    pub fn bind(&mut self, player_id: u32, action: Action, binding: InputBinding) {
+        let bindings = self
+            .bindings
+            .entry(player_id)
+            .or_default()
+            .entry(action)
+            .or_default();
+        if !bindings.contains(&binding) {
+            bindings.push(binding);
+        }
+    }
+
+    pub fn unbind(&mut self, player_id: u32, action: Action, binding: InputBinding) {
+        if let Some(bindings) = self
+            .bindings
+            .get_mut(&player_id)
+            .and_then(|actions| actions.get_mut(&action))
+        {
+            bindings.retain(|b| *b != binding);
+        }
+    }
+
+    /// Remove all bindings of the action
+    pub fn clear_action(&mut self, player_id: u32, action: Action) {
+        if let Some(actions) = self.bindings.get_mut(&player_id) {
+            actions.remove(&action);
+        }
+    }
+
+    pub fn has_player(&self, player_id: u32) -> bool {
+        self.bindings.contains_key(&player_id)
+    }
+
+    pub fn get_bindings(&self, player_id: u32, action: Action) -> &[InputBinding] {
+        self.bindings
+            .get(&player_id)
+            .and_then(|actions| actions.get(&action))
+            .map(|bindings| bindings.as_slice())
+            .unwrap_or_default()
+    }
+
+    /// Axis values below the deadzone are ignored
+    pub fn set_deadzone(&mut self, deadzone: f32) {
+        self.deadzone = deadzone;
+    }
+
+    pub fn get_deadzone(&self) -> f32 {
+        self.deadzone
+    }
+}
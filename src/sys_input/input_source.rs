@@ -0,0 +1,24 @@
+// *************************************************************************
+//
+// Copyright (c) 2025 Andrei Gramakov. All rights reserved.
+//
+// This file is licensed under the terms of the MIT license.
+// For a copy, see: https://opensource.org/licenses/MIT
+//
+// site:    https://agramakov.me
+// e-mail:  mail@agramakov.me
+//
+// *************************************************************************
+use super::InputState;
+
+/// Anything that can provide input: devices, scripts, recordings or bots
+pub trait InputSource {
+    /// Add the current state of the source to the frame `state`
+    fn poll(&mut self, state: &mut InputState);
+}
+
+impl<F: FnMut(&mut InputState)> InputSource for F {
+    fn poll(&mut self, state: &mut InputState) {
+        self(state);
+    }
+}
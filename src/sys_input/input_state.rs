@@ -0,0 +1,153 @@
+// *************************************************************************
+//
+// Copyright (c) 2025 Andrei Gramakov. All rights reserved.
+//
+// This file is licensed under the terms of the MIT license.
+// For a copy, see: https://opensource.org/licenses/MIT
+//
+// site:    https://agramakov.me
+// e-mail:  mail@agramakov.me
+//
+// *************************************************************************
+use super::{
+    input_binding::{parse_axis, parse_button, parse_key},
+    GamepadAxis,
+};
+use gamepads::Button;
+use macroquad::input::KeyCode;
+use std::collections::{BTreeMap, HashMap, HashSet};
+
+/// State of one gamepad during a frame
+#[derive(Debug, Clone, Default, PartialEq)]
+pub struct GamepadState {
+    buttons: HashSet<Button>,
+    axes: HashMap<GamepadAxis, f32>,
+}
+
+impl GamepadState {
+    pub fn press_button(&mut self, button: Button) {
+        self.buttons.insert(button);
+    }
+
+    pub fn is_button_pressed(&self, button: Button) -> bool {
+        self.buttons.contains(&button)
+    }
+
+    /// `value` is in the range [-1.0, 1.0]
+    pub fn set_axis(&mut self, axis: GamepadAxis, value: f32) {
+        self.axes.insert(axis, value);
+    }
+
+    pub fn get_axis(&self, axis: GamepadAxis) -> f32 {
+        self.axes.get(&axis).copied().unwrap_or(0.0)
+    }
+
+    /// Add the pressed buttons of `other`, its axes replace the current ones
+    pub fn merge(&mut self, other: &GamepadState) {
+        self.buttons.extend(other.buttons.iter().copied());
+        self.axes
+            .extend(other.axes.iter().map(|(axis, value)| (*axis, *value)));
+    }
+}
+
+/// Snapshot of all input devices during a frame, filled by the input sources
+#[derive(Debug, Clone, Default, PartialEq)]
+pub struct InputState {
+    keys_down: HashSet<KeyCode>,
+    /// Gamepads by id
+    gamepads: BTreeMap<u8, GamepadState>,
+}
+
+impl InputState {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    pub fn press_key(&mut self, key: KeyCode) {
+        self.keys_down.insert(key);
+    }
+
+    pub fn is_key_down(&self, key: KeyCode) -> bool {
+        self.keys_down.contains(&key)
+    }
+
+    /// Gamepad state, connecting the gamepad if it is not there yet
+    pub fn get_gamepad_mut(&mut self, gamepad_id: u8) -> &mut GamepadState {
+        self.gamepads.entry(gamepad_id).or_default()
+    }
+
+    pub fn get_gamepad(&self, gamepad_id: u8) -> Option<&GamepadState> {
+        self.gamepads.get(&gamepad_id)
+    }
+
+    pub fn get_gamepad_ids(&self) -> Vec<u8> {
+        self.gamepads.keys().copied().collect()
+    }
+
+    /// Add the input of `other`, as if both were pressed at the same time
+    pub fn merge(&mut self, other: &InputState) {
+        self.keys_down.extend(other.keys_down.iter().copied());
+        for (id, gamepad) in &other.gamepads {
+            self.get_gamepad_mut(*id).merge(gamepad);
+        }
+    }
+
+    /// One line representation used by the input recordings:
+    /// `key:Up button:0:ActionDown axis:0:LeftStickX:0.5`
+    pub fn to_record_line(&self) -> String {
+        let mut words: Vec<String> = self
+            .keys_down
+            .iter()
+            .map(|key| format!("key:{:?}", key))
+            .collect();
+        words.sort();
+        for (id, gamepad) in &self.gamepads {
+            let mut buttons: Vec<String> = gamepad
+                .buttons
+                .iter()
+                .map(|button| format!("button:{}:{:?}", id, button))
+                .collect();
+            buttons.sort();
+            words.extend(buttons);
+            for axis in GamepadAxis::ALL {
+                if let Some(value) = gamepad.axes.get(&axis) {
+                    words.push(format!("axis:{}:{:?}:{}", id, axis, value));
+                }
+            }
+            if gamepad.buttons.is_empty() && gamepad.axes.is_empty() {
+                words.push(format!("gamepad:{}", id));
+            }
+        }
+        words.join(" ")
+    }
+
+    pub fn parse_record_line(line: &str) -> Result<InputState, String> {
+        let mut state = InputState::new();
+        for word in line.split_whitespace() {
+            let parts: Vec<&str> = word.split(':').collect();
+            let gamepad_id = |id: &str| {
+                id.parse::<u8>()
+                    .map_err(|_| format!("invalid gamepad id '{}'", id))
+            };
+            match parts.as_slice() {
+                ["key", name] => state.press_key(parse_key(name)?),
+                ["gamepad", id] => {
+                    state.get_gamepad_mut(gamepad_id(id)?);
+                }
+                ["button", id, name] => state
+                    .get_gamepad_mut(gamepad_id(id)?)
+                    .press_button(parse_button(name)?),
+                ["axis", id, name, value] => {
+                    let value = value
+                        .parse()
+                        .map_err(|_| format!("invalid axis value '{}'", value))?;
+                    state
+                        .get_gamepad_mut(gamepad_id(id)?)
+                        .set_axis(parse_axis(name)?, value);
+                }
+                _ => return Err(format!("invalid input '{}'", word)),
+            }
+        }
+        Ok(state)
+    }
+}
@@ -0,0 +1,31 @@
+// *************************************************************************
+//
+// Copyright (c) 2025 Andrei Gramakov. All rights reserved.
+//
+// This file is licensed under the terms of the MIT license.
+// For a copy, see: https://opensource.org/licenses/MIT
+//
+// site:    https://agramakov.me
+// e-mail:  mail@agramakov.me
+//
+// *************************************************************************
+use super::{InputSource, InputState};
+use macroquad::input::get_keys_down;
+
+/// Keyboard of the macroquad window
+#[derive(Default)]
+pub struct KeyboardInput;
+
+impl KeyboardInput {
+    pub fn new() -> Self {
+        Self
+    }
+}
+
+impl InputSource for KeyboardInput {
+    fn poll(&mut self, state: &mut InputState) {
+        for key in get_keys_down() {
+            state.press_key(key);
+        }
+    }
+}
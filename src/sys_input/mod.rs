@@ -9,6 +9,25 @@
 // e-mail:  mail@agramakov.me
 //
 // *************************************************************************
+mod action;
+mod gamepad_input;
+mod input_binding;
+mod input_map;
+mod input_source;
+mod input_state;
+mod keyboard_input;
+mod replay_input;
+mod scripted_input;
+#[allow(clippy::module_inception)]
 mod sys_input;
 
+pub use action::Action;
+pub use gamepad_input::GamepadInput;
+pub use input_binding::{GamepadAxis, InputBinding};
+pub use input_map::InputMap;
+pub use input_source::InputSource;
+pub use input_state::{GamepadState, InputState};
+pub use keyboard_input::KeyboardInput;
+pub use replay_input::ReplayInput;
+pub use scripted_input::ScriptedInput;
 pub use sys_input::SysInput;
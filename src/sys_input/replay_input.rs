@@ -0,0 +1,43 @@
+// *************************************************************************
+//
+// Copyright (c) 2025 Andrei Gramakov. All rights reserved.
+//
+// This file is licensed under the terms of the MIT license.
+// For a copy, see: https://opensource.org/licenses/MIT
+//
+// site:    https://agramakov.me
+// e-mail:  mail@agramakov.me
+//
+// *************************************************************************
+use super::{InputSource, InputState};
+use crate::recording::MatchRecording;
+use std::collections::VecDeque;
+
+/// Plays back the input frames of a recorded match, one frame per poll
+pub struct ReplayInput {
+    frames: VecDeque<InputState>,
+}
+
+impl ReplayInput {
+    pub fn new(recording: &MatchRecording) -> Self {
+        Self {
+            frames: recording
+                .inputs
+                .iter()
+                .map(|(_, frame)| frame.clone())
+                .collect(),
+        }
+    }
+
+    pub fn is_finished(&self) -> bool {
+        self.frames.is_empty()
+    }
+}
+
+impl InputSource for ReplayInput {
+    fn poll(&mut self, state: &mut InputState) {
+        if let Some(frame) = self.frames.pop_front() {
+            state.merge(&frame);
+        }
+    }
+}
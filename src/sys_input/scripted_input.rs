@@ -0,0 +1,49 @@
+// *************************************************************************
+//
+// Copyright (c) 2025 Andrei Gramakov. All rights reserved.
+//
+// This file is licensed under the terms of the MIT license.
+// For a copy, see: https://opensource.org/licenses/MIT
+//
+// site:    https://agramakov.me
+// e-mail:  mail@agramakov.me
+//
+// *************************************************************************
+use super::{InputSource, InputState};
+use std::collections::VecDeque;
+
+/// Plays a prepared sequence of frames, one frame per poll. Gives no input
+/// once the script is over.
+#[derive(Default)]
+pub struct ScriptedInput {
+    frames: VecDeque<InputState>,
+}
+
+impl ScriptedInput {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    pub fn push(&mut self, state: InputState) {
+        self.frames.push_back(state);
+    }
+
+    /// Hold the same input for `count` frames
+    pub fn push_repeated(&mut self, state: InputState, count: usize) {
+        for _ in 0..count {
+            self.frames.push_back(state.clone());
+        }
+    }
+
+    pub fn is_finished(&self) -> bool {
+        self.frames.is_empty()
+    }
+}
+
+impl InputSource for ScriptedInput {
+    fn poll(&mut self, state: &mut InputState) {
+        if let Some(frame) = self.frames.pop_front() {
+            state.merge(&frame);
+        }
+    }
+}
@@ -10,156 +10,214 @@
 //
 // *************************************************************************
 
+use super::{Action, GamepadState, InputBinding, InputMap, InputSource, InputState};
 use crate::{
     base::DynamicAttributes,
     objects::PlayerMode,
     sys_event::{Event, Publisher, SysEvent},
 };
-use gamepads::Gamepads;
-use macroquad::{
-    color::{DARKGRAY, GRAY},
-    input::{is_key_down, is_key_released, KeyCode},
+use std::{
+    collections::HashMap,
+    sync::{Arc, Mutex},
 };
-use std::sync::{Arc, Mutex};
 
 pub struct SysInput {
-    gamepads: Gamepads,
+    sources: Vec<Box<dyn InputSource>>,
     event_bus: Arc<Mutex<SysEvent>>,
+    /// Player ids in the order of registration
+    player_slots: Vec<u32>,
+    input_map: InputMap,
+    /// Player id by gamepad id
+    gamepad_slots: HashMap<u8, u32>,
+    /// Last published `NoClip` state of every player
+    no_clip: HashMap<u32, bool>,
 }
 
 impl SysInput {
+    /// Input system without sources, add them with `add_source`
     pub fn new(event_bus: Arc<Mutex<SysEvent>>) -> Self {
         Self {
             event_bus,
-            gamepads: (Gamepads::new()),
+            sources: Vec::new(),
+            player_slots: Vec::new(),
+            input_map: InputMap::new(),
+            gamepad_slots: HashMap::new(),
+            no_clip: HashMap::new(),
         }
     }
 
-    fn process_gamepads(&mut self) -> (f32, f32, f32, f32) {
-        self.gamepads.poll();
-        let mut gamepad_count = 0;
+    /// The input of all sources is combined
+    pub fn add_source(&mut self, source: Box<dyn InputSource>) {
+        self.sources.push(source);
+    }
 
-        let (mut fx1, mut fy1) = (0.0, 0.0);
-        let (mut fx2, mut fy2) = (0.0, 0.0);
-        for gamepad in self.gamepads.all() {
-            // If A is pressed, change player color
-            if gamepad.is_currently_pressed(gamepads::Button::ActionDown) {
-                self.publish(Event::SetPlayerMode {
-                    id: gamepad_count,
-                    mode: PlayerMode::NoClip,
-                });
-            } else {
-                self.publish(Event::SetPlayerMode {
-                    id: gamepad_count,
-                    mode: PlayerMode::Normal,
-                });
-            }
+    pub fn clear_sources(&mut self) {
+        self.sources.clear();
+    }
 
-            let mut new_fx = 0.0;
-            let gamepad_x = gamepad.left_stick_x();
-            if gamepad_x.abs() >= 0.1 {
-                new_fx = DynamicAttributes::MAX_FORCE * gamepad_x;
-            }
+    /// Register a player to be controlled. Players missing in the input map
+    /// get the default bindings, gamepads are assigned on connection.
+    pub fn add_player_slot(&mut self, player_id: u32) {
+        if self.player_slots.contains(&player_id) {
+            return;
+        }
+        if !self.input_map.has_player(player_id) {
+            self.input_map
+                .add_default_bindings(player_id, self.player_slots.len());
+        }
+        self.player_slots.push(player_id);
+    }
 
-            let mut new_fy = 0.0;
-            let gamepad_y = gamepad.left_stick_y();
-            if gamepad_y.abs() >= 0.1 {
-                new_fy = -DynamicAttributes::MAX_FORCE * gamepad_y;
-            }
+    pub fn get_player_slots(&self) -> &[u32] {
+        &self.player_slots
+    }
 
-            match gamepad_count {
-                0 => {
-                    fx1 = new_fx;
-                    fy1 = new_fy;
-                }
-                1 => {
-                    fx2 = new_fx;
-                    fy2 = new_fy;
-                }
-                _ => {}
-            }
+    pub fn set_input_map(&mut self, input_map: InputMap) {
+        self.input_map = input_map;
+    }
 
-            gamepad_count += 1;
-        }
-        (fx1, fy1, fx2, fy2)
+    pub fn get_input_map(&self) -> &InputMap {
+        &self.input_map
     }
 
-    fn process_keyboard(&mut self) -> (f32, f32, f32, f32) {
-        let (mut fx1, mut fy1) = (0.0, 0.0);
-        let (mut fx2, mut fy2) = (0.0, 0.0);
+    /// For rebinding at runtime
+    pub fn get_input_map_mut(&mut self) -> &mut InputMap {
+        &mut self.input_map
+    }
 
-        // Keyboard input
-        if is_key_down(KeyCode::Right) {
-            fx1 += DynamicAttributes::MAX_FORCE;
-        }
-        if is_key_down(KeyCode::Left) {
-            fx1 -= DynamicAttributes::MAX_FORCE;
-        }
-        if is_key_down(KeyCode::Up) {
-            fy1 -= DynamicAttributes::MAX_FORCE;
-        }
-        if is_key_down(KeyCode::Down) {
-            fy1 += DynamicAttributes::MAX_FORCE;
-        }
-        if is_key_down(KeyCode::RightControl) {
-            self.publish(Event::SetPlayerMode {
-                id: 0,
-                mode: PlayerMode::NoClip,
-            });
-        }
-        if is_key_released(KeyCode::RightControl) {
-            self.publish(Event::SetPlayerMode {
-                id: 0,
-                mode: PlayerMode::Normal,
-            });
-        }
+    /// Control the player with the gamepad, replacing the automatic assignment
+    pub fn assign_gamepad(&mut self, gamepad_id: u8, player_id: u32) {
+        self.gamepad_slots.retain(|_, id| *id != player_id);
+        self.gamepad_slots.insert(gamepad_id, player_id);
+    }
 
-        // Keyboard input
-        if is_key_down(KeyCode::D) {
-            fx2 += DynamicAttributes::MAX_FORCE;
-        }
-        if is_key_down(KeyCode::A) {
-            fx2 -= DynamicAttributes::MAX_FORCE;
-        }
-        if is_key_down(KeyCode::W) {
-            fy2 -= DynamicAttributes::MAX_FORCE;
-        }
-        if is_key_down(KeyCode::S) {
-            fy2 += DynamicAttributes::MAX_FORCE;
-        }
-        if is_key_down(KeyCode::LeftShift) {
-            self.publish(Event::SetPlayerMode {
-                id: 1,
-                mode: PlayerMode::NoClip,
-            });
+    pub fn get_gamepad_player(&self, gamepad_id: u8) -> Option<u32> {
+        self.gamepad_slots.get(&gamepad_id).copied()
+    }
+
+    /// Give a newly connected gamepad to the first player without one
+    fn auto_assign_gamepad(&mut self, gamepad_id: u8) -> Option<u32> {
+        let free_slot = self
+            .player_slots
+            .iter()
+            .find(|id| !self.gamepad_slots.values().any(|taken| taken == *id))
+            .copied()?;
+        log::debug!("Gamepad {} assigned to player {}", gamepad_id, free_slot);
+        self.gamepad_slots.insert(gamepad_id, free_slot);
+        Some(free_slot)
+    }
+
+    fn poll_sources(&mut self) -> InputState {
+        let mut state = InputState::new();
+        for source in &mut self.sources {
+            source.poll(&mut state);
         }
-        if is_key_released(KeyCode::LeftShift) {
-            self.publish(Event::SetPlayerMode {
-                id: 1,
-                mode: PlayerMode::Normal,
-            });
+        state
+    }
+
+    /// Gamepad id of every player, assigning the newly connected gamepads
+    fn assign_gamepads(&mut self, state: &InputState) -> HashMap<u32, u8> {
+        let mut player_gamepads = HashMap::new();
+        for gamepad_id in state.get_gamepad_ids() {
+            let player_id = match self.get_gamepad_player(gamepad_id) {
+                Some(id) => id,
+                None => match self.auto_assign_gamepad(gamepad_id) {
+                    Some(id) => id,
+                    None => continue,
+                },
+            };
+            player_gamepads.insert(player_id, gamepad_id);
         }
+        player_gamepads
+    }
 
-        (fx1, fy1, fx2, fy2)
-    } // process keyboard
+    /// Value of the binding in the range [0.0, 1.0]
+    fn read_binding(
+        &self,
+        binding: &InputBinding,
+        state: &InputState,
+        gamepad: Option<&GamepadState>,
+    ) -> f32 {
+        match (binding, gamepad) {
+            (InputBinding::Key(key), _) => state.is_key_down(*key) as u8 as f32,
+            (InputBinding::GamepadButton(button), Some(gamepad)) => {
+                gamepad.is_button_pressed(*button) as u8 as f32
+            }
+            (InputBinding::GamepadAxis { axis, positive }, Some(gamepad)) => {
+                let value = gamepad.get_axis(*axis);
+                let value = if *positive { value } else { -value };
+                if value >= self.input_map.get_deadzone() {
+                    value
+                } else {
+                    0.0
+                }
+            }
+            (_, None) => 0.0,
+        }
+    }
 
-    pub fn read_input(&mut self) {
-        let (g_fx1, g_fy1, g_fx2, g_fy2) = self.process_gamepads();
-        let (k_fx1, k_fy1, k_fx2, k_fy2) = self.process_keyboard();
+    /// Strongest of the inputs bound to the action
+    fn read_action(
+        &self,
+        player_id: u32,
+        action: Action,
+        state: &InputState,
+        gamepad: Option<&GamepadState>,
+    ) -> f32 {
+        self.input_map
+            .get_bindings(player_id, action)
+            .iter()
+            .map(|binding| self.read_binding(binding, state, gamepad))
+            .fold(0.0, f32::max)
+    }
 
-        let (fx1, fy1, fx2, fy2) = (g_fx1 + k_fx1, g_fy1 + k_fy1, g_fx2 + k_fx2, g_fy2 + k_fy2);
+    /// Publish the input of every player and return the polled frame.
+    /// Nothing is published without sources, so the events published by
+    /// the game are not overwritten.
+    pub fn read_input(&mut self) -> Option<InputState> {
+        if self.sources.is_empty() {
+            return None;
+        }
+        let state = self.poll_sources();
+        self.publish_input(&state);
+        Some(state)
+    }
 
-        self.publish(Event::MovePlayer {
-            id: 0,
-            fx: fx1,
-            fy: fy1,
-        });
+    /// Publish the input of every player from the frame, e.g. a recorded one
+    pub fn publish_input(&mut self, state: &InputState) {
+        let gamepads = self.assign_gamepads(state);
+
+        for player_id in self.player_slots.clone() {
+            let gamepad = gamepads
+                .get(&player_id)
+                .and_then(|gamepad_id| state.get_gamepad(*gamepad_id));
+            let read = |action| self.read_action(player_id, action, state, gamepad);
+
+            let fx =
+                DynamicAttributes::MAX_FORCE * (read(Action::MoveRight) - read(Action::MoveLeft));
+            let fy = DynamicAttributes::MAX_FORCE * (read(Action::MoveDown) - read(Action::MoveUp));
+            let no_clip = read(Action::NoClip) > 0.0;
+
+            self.publish(Event::MovePlayer {
+                id: player_id,
+                fx,
+                fy,
+            });
 
-        self.publish(Event::MovePlayer {
-            id: 1,
-            fx: fx2,
-            fy: fy2,
-        });
+            // The mode is published only when it changes
+            if self.no_clip.get(&player_id).copied().unwrap_or(false) != no_clip {
+                self.no_clip.insert(player_id, no_clip);
+                let mode = if no_clip {
+                    PlayerMode::NoClip
+                } else {
+                    PlayerMode::Normal
+                };
+                self.publish(Event::SetPlayerMode {
+                    id: player_id,
+                    mode,
+                });
+            }
+        }
     }
 } // impl
 
@@ -11,7 +11,7 @@
 // *************************************************************************
 
 use macroquad::{
-    color::{RED, WHITE},
+    color::WHITE,
     text::{draw_text_ex, TextParams},
 };
 
@@ -19,6 +19,12 @@ pub struct Osd {
     text: String,
 }
 
+impl Default for Osd {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
 impl Osd {
     pub fn new() -> Self {
         Self {
@@ -0,0 +1,105 @@
+// *************************************************************************
+//
+// Copyright (c) 2025 Andrei Gramakov. All rights reserved.
+//
+// This file is licensed under the terms of the MIT license.
+// For a copy, see: https://opensource.org/licenses/MIT
+//
+// site:    https://agramakov.me
+// e-mail:  mail@agramakov.me
+//
+// *************************************************************************
+
+/// Splits the variable frame time into fixed simulation ticks
+pub struct FixedTimestep {
+    tick_duration: f32,
+    max_catch_up_steps: u32,
+    accumulator: f32,
+}
+
+impl Default for FixedTimestep {
+    fn default() -> Self {
+        Self::new(
+            FixedTimestep::DEFAULT_TICK_RATE,
+            FixedTimestep::DEFAULT_MAX_CATCH_UP_STEPS,
+        )
+    }
+}
+
+impl FixedTimestep {
+    pub const DEFAULT_TICK_RATE: f32 = 60.0;
+    pub const DEFAULT_MAX_CATCH_UP_STEPS: u32 = 5;
+
+    /// Panics if the tick rate is not a positive number or no catch up
+    /// steps are allowed
+    pub fn new(tick_rate: f32, max_catch_up_steps: u32) -> Self {
+        Self {
+            tick_duration: Self::get_valid_tick_duration(tick_rate),
+            max_catch_up_steps: Self::get_valid_max_catch_up_steps(max_catch_up_steps),
+            accumulator: 0.0,
+        }
+    }
+
+    /// Set the number of ticks per second, panics if it is not a positive
+    /// number
+    pub fn set_tick_rate(&mut self, tick_rate: f32) {
+        self.tick_duration = Self::get_valid_tick_duration(tick_rate);
+    }
+
+    fn get_valid_tick_duration(tick_rate: f32) -> f32 {
+        assert!(
+            tick_rate > 0.0 && tick_rate.is_finite(),
+            "invalid tick rate {}",
+            tick_rate
+        );
+        1.0 / tick_rate
+    }
+
+    pub fn get_tick_rate(&self) -> f32 {
+        1.0 / self.tick_duration
+    }
+
+    pub fn get_tick_duration(&self) -> f32 {
+        self.tick_duration
+    }
+
+    /// Set how many ticks can be simulated in one frame to catch up with
+    /// the real time. The rest of the time is dropped, so the simulation
+    /// slows down instead of freezing on slow machines. Panics on zero,
+    /// the simulation would never advance.
+    pub fn set_max_catch_up_steps(&mut self, max_catch_up_steps: u32) {
+        self.max_catch_up_steps = Self::get_valid_max_catch_up_steps(max_catch_up_steps);
+    }
+
+    fn get_valid_max_catch_up_steps(max_catch_up_steps: u32) -> u32 {
+        assert!(max_catch_up_steps > 0, "no catch up steps allowed");
+        max_catch_up_steps
+    }
+
+    pub fn get_max_catch_up_steps(&self) -> u32 {
+        self.max_catch_up_steps
+    }
+
+    /// Add the frame time and return the number of ticks to simulate
+    pub fn advance(&mut self, frame_time: f32) -> u32 {
+        self.accumulator += frame_time;
+
+        let mut steps = 0;
+        while self.accumulator >= self.tick_duration && steps < self.max_catch_up_steps {
+            self.accumulator -= self.tick_duration;
+            steps += 1;
+        }
+
+        if self.accumulator >= self.tick_duration {
+            // Too far behind, drop the time we can't catch up with
+            self.accumulator %= self.tick_duration;
+        }
+        steps
+    }
+
+    /// Fraction of the next tick that has already passed, in [0.0, 1.0).
+    /// Used to interpolate positions between the last two ticks.
+    pub fn get_alpha(&self) -> f32 {
+        self.accumulator / self.tick_duration
+    }
+}
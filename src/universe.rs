@@ -10,15 +10,28 @@
 //
 // *************************************************************************
 use crate::{
-    objects::{Collectable, Player, Wall},
-    sys_collision::SysCollision,
-    sys_event::{Event, Subscriber, SysEvent},
-    sys_input::SysInput,
+    entity::{Entity, EntityId},
+    level::{Level, LevelGenerator, TiledBackground},
+    objects::{Collectable, Player, Wall, Zone},
+    recording::MatchRecording,
+    rules::{GameResult, GameRules, TimeAttack},
+    sys_collision::{
+        Collidable, CollidableType, CollisionFilter, CollisionShape, PairFilter, PhysicsMaterial,
+        QueryFilter, RayHit, SysCollision,
+    },
+    sys_event::{Event, EventSender, Subscriber, SubscriptionId, SysEvent},
+    sys_input::{GamepadInput, InputMap, InputSource, KeyboardInput, SysInput},
     sys_osd::Osd,
+    timestep::FixedTimestep,
     world::World,
 };
+use ::rand::{rngs::StdRng, Rng, SeedableRng};
 use macroquad::prelude::*;
-use std::sync::{Arc, Mutex};
+use std::{
+    any::Any,
+    ops::Range,
+    sync::{Arc, Mutex},
+};
 
 pub struct Universe {
     sys_event: Arc<Mutex<SysEvent>>,
@@ -26,28 +39,251 @@ pub struct Universe {
     sys_collision: SysCollision,
     sys_osd: Osd,
     world: World,
+    timestep: FixedTimestep,
+    /// Taken out while the rules are updated, so they can access the universe
+    rules: Option<Box<dyn GameRules>>,
+    /// Fixed screen size in headless mode, the window size is used otherwise
+    screen_size: Option<(f32, f32)>,
+    elapsed_time: f32,
+    tick_count: u64,
+    random_seed: u64,
+    /// Source of all random spawns, a seed fully determines the level
+    rng: StdRng,
+    /// Where `add_random_collectible` puts collectibles, anywhere if empty
+    collectible_spawn_points: Vec<(f32, f32)>,
+    recording: Option<(u64, MatchRecording)>,
+    replay: Option<(u64, MatchRecording)>,
+    /// Drawn behind the objects
+    background: Option<TiledBackground>,
     game_over: bool,
 }
 const REFERENCE_HEIGHT: f32 = 600.0;
-const TIMER: u32 = 30;
+
+impl Default for Universe {
+    fn default() -> Self {
+        Self::new()
+    }
+}
 
 impl Universe {
     pub fn new() -> Self {
+        let mut universe = Self::new_headless((REFERENCE_HEIGHT, REFERENCE_HEIGHT));
+        universe.add_input_source(Box::new(KeyboardInput::new()));
+        universe.add_input_source(Box::new(GamepadInput::new()));
+        universe.screen_size = None;
+        universe
+    }
+
+    /// Create a universe that does not need a window. It has to be advanced
+    /// manually with `step`. There are no input sources, the input can be
+    /// scripted with `add_input_source` or injected with `publish_event`.
+    pub fn new_headless(screen_size_wh: (f32, f32)) -> Self {
         let sys_event = Arc::new(Mutex::new(SysEvent::new()));
         let sys_input = SysInput::new(sys_event.clone());
         let sys_collision = SysCollision::new(sys_event.clone());
         let sys_osd = Osd::new();
         let world = World::new();
+        let random_seed = ::rand::thread_rng().gen();
         Self {
             sys_event,
             sys_input,
             sys_collision,
             sys_osd,
             world,
+            timestep: FixedTimestep::default(),
+            rules: Some(Box::new(TimeAttack::default())),
+            screen_size: Some(screen_size_wh),
+            elapsed_time: 0.0,
+            tick_count: 0,
+            random_seed,
+            rng: StdRng::seed_from_u64(random_seed),
+            collectible_spawn_points: Vec::new(),
+            recording: None,
+            replay: None,
+            background: None,
             game_over: false,
         }
     }
 
+    pub fn is_headless(&self) -> bool {
+        self.screen_size.is_some()
+    }
+
+    pub fn get_screen_size(&self) -> (f32, f32) {
+        match self.screen_size {
+            Some(size) => size,
+            None => (screen_width(), screen_height()),
+        }
+    }
+
+    /// Set the number of simulation ticks per second used by `run`, panics
+    /// if it is not a positive number
+    pub fn set_tick_rate(&mut self, tick_rate: f32) {
+        self.timestep.set_tick_rate(tick_rate);
+    }
+
+    pub fn get_tick_rate(&self) -> f32 {
+        self.timestep.get_tick_rate()
+    }
+
+    /// Size of the collision broad phase cells, about the size of the
+    /// moving objects. Panics if it is not a positive number.
+    pub fn set_collision_cell_size(&mut self, cell_size: f32) {
+        self.sys_collision.set_cell_size(cell_size);
+    }
+
+    /// Set how many ticks `run` may simulate in one frame to catch up,
+    /// panics on zero
+    pub fn set_max_catch_up_steps(&mut self, max_catch_up_steps: u32) {
+        self.timestep.set_max_catch_up_steps(max_catch_up_steps);
+    }
+
+    pub fn get_elapsed_time(&self) -> f32 {
+        self.elapsed_time
+    }
+
+    /// Replace the game mode, `TimeAttack` is used by default
+    pub fn set_rules(&mut self, rules: Box<dyn GameRules>) {
+        self.rules = Some(rules);
+    }
+
+    pub fn is_game_over(&self) -> bool {
+        self.game_over
+    }
+
+    /// Result of the game according to the rules, final once the game is over
+    pub fn get_result(&self) -> GameResult {
+        match &self.rules {
+            Some(rules) => rules.get_result(self),
+            None => GameResult::from_scores(self),
+        }
+    }
+
+    pub fn get_player_ids(&self) -> Vec<u32> {
+        self.world.get_player_ids()
+    }
+
+    pub fn get_player_score(&self, player_id: u32) -> u32 {
+        self.world.get_player_score(player_id)
+    }
+
+    pub fn get_player_position(&self, player_id: u32) -> Option<(f32, f32)> {
+        self.world.get_player_position(player_id)
+    }
+
+    /// Entity id of the player, for the calls taking an `EntityId`
+    pub fn find_player(&self, player_id: u32) -> Option<EntityId> {
+        self.world.find_player(player_id)
+    }
+
+    pub fn iter_players(&self) -> impl Iterator<Item = (EntityId, &Player)> {
+        self.world.iter_players()
+    }
+
+    /// Set the input bindings of all players
+    pub fn set_input_map(&mut self, input_map: InputMap) {
+        self.sys_input.set_input_map(input_map);
+    }
+
+    /// For rebinding at runtime
+    pub fn get_input_map_mut(&mut self) -> &mut InputMap {
+        self.sys_input.get_input_map_mut()
+    }
+
+    /// Control the player with the gamepad, see `GamepadInput` for the ids
+    pub fn assign_gamepad(&mut self, gamepad_id: u8, player_id: u32) {
+        self.sys_input.assign_gamepad(gamepad_id, player_id);
+    }
+
+    /// Add a source of the player input, the input of all sources is combined
+    pub fn add_input_source(&mut self, source: Box<dyn InputSource>) {
+        self.sys_input.add_source(source);
+    }
+
+    /// Remove all input sources, e.g. to replace the devices with a replay
+    pub fn clear_input_sources(&mut self) {
+        self.sys_input.clear_sources();
+    }
+
+    pub fn get_random_seed(&self) -> u64 {
+        self.random_seed
+    }
+
+    /// Restart the random sequence. A seed and the same order of the
+    /// `add_random_*` calls give the same level.
+    pub fn set_random_seed(&mut self, seed: u64) {
+        self.random_seed = seed;
+        self.rng = StdRng::seed_from_u64(seed);
+    }
+
+    /// Generator used for all random spawns, for the game specific randomness
+    /// that has to be reproducible too
+    pub fn get_rng(&mut self) -> &mut StdRng {
+        &mut self.rng
+    }
+
+    /// Record the input polled before every tick from now on, together with
+    /// the random seed. Start before the first tick to replay the whole match.
+    pub fn start_recording(&mut self) {
+        let recording = MatchRecording::new(self.random_seed, self.get_tick_rate());
+        self.recording = Some((self.tick_count, recording));
+    }
+
+    pub fn stop_recording(&mut self) -> Option<MatchRecording> {
+        self.recording.take().map(|(_, recording)| recording)
+    }
+
+    pub fn get_recording(&self) -> Option<&MatchRecording> {
+        self.recording.as_ref().map(|(_, recording)| recording)
+    }
+
+    /// Play the recorded input back instead of reading the input sources.
+    /// The events published by the game itself are not recorded, they are
+    /// published again by the replayed match. Call it before building the
+    /// level, and build the level the same way as in the recorded match.
+    pub fn start_replay(&mut self, recording: MatchRecording) {
+        self.set_random_seed(recording.seed);
+        self.set_tick_rate(recording.tick_rate);
+        self.replay = Some((self.tick_count, recording));
+    }
+
+    pub fn is_replaying(&self) -> bool {
+        self.replay.is_some()
+    }
+
+    /// Put an event on the bus, it is processed on the next step. It can be
+    /// an `Event` or an event type of the game, see `subscribe_to`.
+    pub fn publish_event<E: Any + Send>(&mut self, event: E) {
+        self.sys_event.lock().unwrap().publish(event);
+    }
+
+    /// Receive every `Event`, e.g. the collisions for sounds and effects
+    pub fn subscribe(&mut self, subscriber: Arc<Mutex<dyn Subscriber>>) -> SubscriptionId {
+        self.sys_event.lock().unwrap().subscribe(subscriber)
+    }
+
+    /// Call the handler only for the events of the type `E`, e.g. a game
+    /// defined event struct. The handler publishes follow-up events with
+    /// `get_event_sender`, not to the universe, they are processed on the
+    /// next step.
+    pub fn subscribe_to<E: Any, F: FnMut(&E) + Send + 'static>(
+        &mut self,
+        handler: F,
+    ) -> SubscriptionId {
+        self.sys_event.lock().unwrap().subscribe_to(handler)
+    }
+
+    /// `false` if the handler is already unsubscribed
+    pub fn unsubscribe(&mut self, id: SubscriptionId) -> bool {
+        self.sys_event.lock().unwrap().unsubscribe(id)
+    }
+
+    /// Publishes from the handlers and subscribers, the events are
+    /// processed in the same step
+    pub fn get_event_sender(&self) -> EventSender {
+        self.sys_event.lock().unwrap().get_sender()
+    }
+
     pub fn add_player(
         &mut self,
         player_id: u32,
@@ -56,101 +292,353 @@ impl Universe {
         mass: f32,
         friction: f32,
         color: Color,
-    ) {
-        let player = Arc::new(Mutex::new(Player::new(
-            player_id,
-            0,
-            position_xy,
-            size_wh,
-            mass,
-            friction,
-            color,
-        )));
-        self.world.add_player_object(player.clone());
-        self.sys_event.lock().unwrap().subscribe(player.clone());
-        self.sys_collision.add_collidable_object(player.clone());
-    }
-
-    pub fn add_wall(&mut self, position_xy: (f32, f32), size_wh: (f32, f32)) {
-        let object = Arc::new(Mutex::new(Wall::new(0, position_xy, size_wh)));
-        self.push_static_object(object);
-    }
-
-    pub fn add_random_collectible(&mut self) {
-        let object = Arc::new(Mutex::new(Collectable::random((
-            20.0..screen_width() - 20.0,
-            20.0..screen_height() - 20.0,
-        ))));
-        self.world.add_collectable_object(object.clone());
-        self.sys_collision.add_collidable_object(object.clone());
+    ) -> EntityId {
+        let player = Player::new(player_id, position_xy, size_wh, mass, friction, color);
+        self.sys_input.add_player_slot(player_id);
+        self.world.add_object(player)
     }
 
-    fn cleanup_objects(&mut self) {
-        self.world.cleanup_objects();
-        self.sys_collision.cleanup_objects();
+    /// Returns the entity id, e.g. for `set_collision_filter`
+    pub fn add_wall(&mut self, position_xy: (f32, f32), size_wh: (f32, f32)) -> EntityId {
+        self.world.add_object(Wall::new(position_xy, size_wh))
     }
 
-    fn push_static_object(&mut self, object: Arc<Mutex<Wall>>) {
-        self.world.add_object(object.clone());
-        self.sys_collision.add_static_object(object.clone());
+    /// Wall of any shape, e.g. an angled one, centred in the bounding box
+    pub fn add_shaped_wall(
+        &mut self,
+        position_xy: (f32, f32),
+        size_wh: (f32, f32),
+        shape: CollisionShape,
+    ) -> EntityId {
+        self.world
+            .add_object(Wall::new_shaped(position_xy, size_wh, shape))
     }
 
-    pub fn add_random_wall(&mut self) {
-        let object = Arc::new(Mutex::new(Wall::random(
-            0,
-            (0.0..screen_width(), 0.0..screen_height()),
-            (30.0..100.0, 30.0..100.0),
-        )));
-        self.push_static_object(object);
+    /// Sensor area drawn in the color, returns the entity id. The objects
+    /// inside are reported by the sensor events and `get_sensor_contacts`.
+    pub fn add_zone(
+        &mut self,
+        position_xy: (f32, f32),
+        size_wh: (f32, f32),
+        shape: CollisionShape,
+        color: Color,
+    ) -> EntityId {
+        self.world
+            .add_object(Zone::new(position_xy, size_wh, shape, color))
     }
 
-    pub async fn run(&mut self) -> (u32, u32) {
-        let (mut p0, mut p1) = (0, 0);
-        let mut timer = TIMER;
-        let mut last_time = get_time();
-        loop {
-            let scale = screen_height() / REFERENCE_HEIGHT;
+    /// `None` if the entity was removed, e.g. a collected collectible
+    pub fn get(&self, id: EntityId) -> Option<&dyn Entity> {
+        self.world.get(id)
+    }
 
-            self.sys_input.read_input();
-            self.sys_event.lock().unwrap().process_all();
+    pub fn get_mut(&mut self, id: EntityId) -> Option<&mut (dyn Entity + 'static)> {
+        self.world.get_mut(id)
+    }
 
-            clear_background(BLACK);
-            self.cleanup_objects();
+    pub fn contains(&self, id: EntityId) -> bool {
+        self.world.contains(id)
+    }
+
+    /// Ids of the entities of the collidable type, e.g. all collectibles
+    pub fn find_by_type(&self, collidable_type: CollidableType) -> Vec<EntityId> {
+        self.world.find_by_type(collidable_type)
+    }
+
+    /// Top left corner of the entity
+    pub fn get_position(&self, id: EntityId) -> Option<(f32, f32)> {
+        self.world.get(id).map(|entity| entity.get_position())
+    }
+
+    /// Teleport the entity, it is not swept through the objects on the way.
+    /// `false` if it is already removed.
+    pub fn set_position(&mut self, id: EntityId, position_xy: (f32, f32)) -> bool {
+        self.world.set_position(id, position_xy)
+    }
+
+    pub fn get_size(&self, id: EntityId) -> Option<(f32, f32)> {
+        self.world.get_size(id)
+    }
+
+    /// The collision shape is scaled to the new size. `false` if it is
+    /// already removed, panics if the size is not positive.
+    pub fn set_size(&mut self, id: EntityId, size_wh: (f32, f32)) -> bool {
+        self.world.set_size(id, size_wh)
+    }
+
+    /// Ids of the objects overlapping the sensor after the last step
+    pub fn get_sensor_contacts(&self, sensor_id: EntityId) -> Vec<EntityId> {
+        self.sys_collision.get_sensor_contacts(sensor_id)
+    }
+
+    /// Ids of the objects at the point, e.g. under the mouse
+    pub fn query_point(&self, point_xy: (f32, f32), filter: &QueryFilter) -> Vec<EntityId> {
+        self.sys_collision
+            .query_point(self.world.get_entities(), point_xy, filter)
+    }
+
+    /// Ids of the objects within the radius, the nearest first
+    pub fn query_radius(
+        &self,
+        center_xy: (f32, f32),
+        radius: f32,
+        filter: &QueryFilter,
+    ) -> Vec<EntityId> {
+        self.sys_collision
+            .query_radius(self.world.get_entities(), center_xy, radius, filter)
+    }
+
+    /// The first object on the ray within the distance
+    pub fn cast_ray(
+        &self,
+        origin_xy: (f32, f32),
+        direction_xy: (f32, f32),
+        max_distance: f32,
+        filter: &QueryFilter,
+    ) -> Option<RayHit> {
+        self.sys_collision.cast_ray(
+            self.world.get_entities(),
+            origin_xy,
+            direction_xy,
+            max_distance,
+            filter,
+        )
+    }
+
+    /// Which objects the object interacts with, e.g. walls only for a team
+    pub fn set_collision_filter(&mut self, id: EntityId, filter: CollisionFilter) {
+        self.sys_collision
+            .set_collision_filter(self.world.get_entities_mut(), id, filter);
+    }
+
+    /// The filter of the normal mode, the no clip mode also ignores walls
+    pub fn set_player_collision_filter(&mut self, player_id: u32, filter: CollisionFilter) {
+        if let Some(player) = self.world.get_player_mut(player_id) {
+            player.set_collision_filter(filter);
+        }
+    }
+
+    /// Restitution and friction of the object in collisions
+    pub fn set_physics_material(&mut self, id: EntityId, material: PhysicsMaterial) {
+        if let Some(object) = self.world.get_mut(id) {
+            object.set_physics_material(material);
+        }
+    }
+
+    pub fn set_player_physics_material(&mut self, player_id: u32, material: PhysicsMaterial) {
+        if let Some(player) = self.world.get_player_mut(player_id) {
+            player.set_physics_material(material);
+        }
+    }
+
+    /// Continuous collision detection against static objects, for fast objects
+    pub fn set_ccd_enabled(&mut self, id: EntityId, is_ccd_enabled: bool) {
+        if let Some(object) = self.world.get_mut(id) {
+            object.set_ccd_enabled(is_ccd_enabled);
+        }
+    }
+
+    /// Enabled for players by default
+    pub fn set_player_ccd_enabled(&mut self, player_id: u32, is_ccd_enabled: bool) {
+        if let Some(player) = self.world.get_player_mut(player_id) {
+            player.set_ccd_enabled(is_ccd_enabled);
+        }
+    }
+
+    /// Decide for every pair of objects accepted by their filters, if they
+    /// interact
+    pub fn set_pair_filter(&mut self, pair_filter: Option<PairFilter>) {
+        self.sys_collision.set_pair_filter(pair_filter);
+    }
+
+    /// Change the collision shape of a player, the bounding box stays
+    pub fn set_player_shape(&mut self, player_id: u32, shape: CollisionShape) {
+        if let Some(player) = self.world.get_player_mut(player_id) {
+            player.set_shape(shape);
+        }
+    }
+
+    pub fn add_collectible(&mut self, position_xy: (f32, f32)) -> EntityId {
+        self.world
+            .add_object(Collectable::new(position_xy.0, position_xy.1))
+    }
+
+    pub fn add_random_collectible(&mut self) -> EntityId {
+        if !self.collectible_spawn_points.is_empty() {
+            let index = self.rng.gen_range(0..self.collectible_spawn_points.len());
+            return self.add_collectible(self.collectible_spawn_points[index]);
+        }
+        let (width, height) = self.get_screen_size();
+        let position_xy = (
+            Self::get_spawn_range(width, 20.0),
+            Self::get_spawn_range(height, 20.0),
+        );
+        let object = Collectable::random(&mut self.rng, position_xy);
+        self.world.add_object(object)
+    }
+
+    /// Positions `margin` away from both borders, the middle if the length is
+    /// too short for the margin
+    fn get_spawn_range(length: f32, margin: f32) -> Range<f32> {
+        if length > 2.0 * margin {
+            margin..length - margin
+        } else {
+            let middle = length.max(0.0) / 2.0;
+            middle..middle + 1.0
+        }
+    }
+
+    /// Tiles drawn behind the objects, usually of the map the level was
+    /// loaded from
+    pub fn set_background(&mut self, background: Option<TiledBackground>) {
+        self.background = background;
+    }
+
+    /// Add the walls and the collectibles of the level. The players are
+    /// added separately, at `level.player_spawns`.
+    pub fn add_level(&mut self, level: &Level) {
+        for (position_xy, size_wh) in &level.walls {
+            self.add_wall(*position_xy, *size_wh);
+        }
+        for position_xy in &level.collectibles {
+            self.add_collectible(*position_xy);
+        }
+        self.collectible_spawn_points = level.collectible_spawn_points.clone();
+        self.sys_collision
+            .bake_static_objects(self.world.get_entities());
+    }
 
-            self.world.update(get_frame_time());
-            // We process collisions after updating the positions
-            self.sys_collision.process_collisions();
+    /// Generate a level with the universe random generator, so the seed
+    /// determines the level. `None` if the generator gave up.
+    pub fn generate_level(&mut self, generator: &LevelGenerator) -> Option<Level> {
+        generator.generate(&mut self.rng)
+    }
 
-            let new_p0 = self.world.get_player_score(0);
-            let new_p1 = self.world.get_player_score(1);
+    /// Remove the entity at the start of the next step, see `World::despawn`.
+    /// `false` if it is already removed.
+    pub fn despawn(&mut self, id: EntityId) -> bool {
+        self.world.despawn(id)
+    }
 
-            for _ in 0..((new_p0 - p0) + (new_p1 - p1)) {
-                self.add_random_collectible();
+    /// Remove the entity right away, unlike `despawn`. `false` if it is
+    /// already removed.
+    pub fn remove(&mut self, id: EntityId) -> bool {
+        match self.world.remove(id) {
+            Some(object_type) => {
+                self.forget_entities(vec![(id, object_type)]);
+                true
             }
-            p0 = new_p0;
-            p1 = new_p1;
-
-            // Timer, score, etc.
-            let current_time = get_time();
-            if current_time - last_time > 1.0 {
-                last_time = current_time;
-                timer -= 1;
+            None => false,
+        }
+    }
+
+    fn cleanup_objects(&mut self) {
+        let despawned = self.world.cleanup_objects();
+        self.forget_entities(despawned);
+    }
+
+    /// Drop the removed entities from the systems, the subscribers get
+    /// `EntityDespawned` with the next events
+    fn forget_entities(&mut self, despawned: Vec<(EntityId, CollidableType)>) {
+        let ids: Vec<EntityId> = despawned.iter().map(|(id, _)| *id).collect();
+        self.sys_collision
+            .remove_entities(self.world.get_entities_mut(), &ids);
+
+        let mut sys_event = self.sys_event.lock().unwrap();
+        for (id, object_type) in despawned {
+            sys_event.publish(Event::EntityDespawned { id, object_type });
+        }
+        sys_event.cleanup_subscribers();
+    }
+
+    pub fn add_random_wall(&mut self) -> EntityId {
+        let (width, height) = self.get_screen_size();
+        let object = Wall::random(
+            &mut self.rng,
+            (0.0..width, 0.0..height),
+            (30.0..100.0, 30.0..100.0),
+        );
+        self.world.add_object(object)
+    }
+
+    fn read_input(&mut self) {
+        // The recorded input is published by the tick instead
+        if self.replay.is_some() {
+            return;
+        }
+        if let Some(frame) = self.sys_input.read_input() {
+            if let Some((start_tick, recording)) = &mut self.recording {
+                // Published now, processed on the next tick
+                recording.push_input(self.tick_count - *start_tick, frame);
             }
-            if timer == 0 {
-                self.game_over = true;
+        }
+    }
+
+    /// Advance the simulation by `delta_time` seconds without touching the window
+    pub fn step(&mut self, delta_time: f32) {
+        self.read_input();
+        self.tick(delta_time);
+    }
+
+    fn tick(&mut self, delta_time: f32) {
+        if let Some((start_tick, replay)) = &self.replay {
+            for frame in replay.get_tick_inputs(self.tick_count - start_tick) {
+                self.sys_input.publish_input(frame);
             }
+        }
+        self.sys_event
+            .lock()
+            .unwrap()
+            .process_all(self.world.get_entities_mut());
 
-            self.sys_osd
-                .set_text(format!("TIME: {}, RED:{} BLUE:{}", timer, p0, p1).as_str());
+        self.cleanup_objects();
 
-            self.world.draw(scale);
-            self.sys_osd.draw(scale);
+        self.world.update(delta_time);
+        // We process collisions after updating the positions
+        self.sys_collision
+            .process_collisions(self.world.get_entities_mut());
+
+        // Timer, score, etc.
+        self.elapsed_time += delta_time;
+        self.tick_count += 1;
+        if let Some(mut rules) = self.rules.take() {
+            rules.update(self, delta_time);
+            self.game_over = rules.is_game_over(self);
+            self.sys_osd.set_text(rules.get_status_text(self).as_str());
+            self.rules = Some(rules);
+        }
+    }
+
+    pub fn draw(&self) {
+        let scale = self.get_screen_size().1 / REFERENCE_HEIGHT;
+        if let Some(background) = &self.background {
+            background.draw(scale);
+        }
+        self.world.draw(scale, self.timestep.get_alpha());
+        self.sys_osd.draw(scale);
+    }
+
+    pub async fn run(&mut self) -> GameResult {
+        loop {
+            // The input is read once per frame, so no key press is lost on
+            // frames without ticks
+            self.read_input();
+            let ticks = self.timestep.advance(get_frame_time());
+            for _ in 0..ticks {
+                self.tick(self.timestep.get_tick_duration());
+                if self.game_over {
+                    break;
+                }
+            }
+
+            clear_background(BLACK);
+            self.draw();
             next_frame().await;
 
             if self.game_over {
                 break;
             }
         }
-        (p0, p1)
+        self.get_result()
     }
 }
@@ -1,5 +1,3 @@
-use macroquad::window::screen_height;
-
 // *************************************************************************
 //
 // Copyright (c) 2025 Andrei Gramakov. All rights reserved.
@@ -12,107 +10,217 @@ use macroquad::window::screen_height;
 //
 // *************************************************************************
 use crate::{
-    base::{AsAny, Dynamic, GameObject},
-    objects::{Collectable, Player},
+    base::{Dynamic, GameObject},
+    entity::{Entity, EntityId, EntityStore},
+    objects::Player,
+    sys_collision::{BodyType, CollidableType},
 };
-use std::sync::{Arc, Mutex};
 
 pub struct World {
-    players: Arc<Mutex<Vec<Arc<Mutex<Player>>>>>,
-    objects: Vec<Arc<Mutex<dyn GameObject>>>,
-    collectables: Vec<Arc<Mutex<Collectable>>>,
-    next_object_id: u32,
+    entities: EntityStore,
+}
+
+impl Default for World {
+    fn default() -> Self {
+        Self::new()
+    }
 }
 
 impl World {
     pub fn new() -> Self {
         Self {
-            players: Arc::new(Mutex::new(Vec::new())),
-            objects: Vec::new(),
-            collectables: Vec::new(),
-            next_object_id: 0,
+            entities: EntityStore::new(),
         }
     }
 
+    /// All objects, for the systems
+    pub fn get_entities(&self) -> &EntityStore {
+        &self.entities
+    }
+
+    pub fn get_entities_mut(&mut self) -> &mut EntityStore {
+        &mut self.entities
+    }
+
+    /// `None` if the entity was removed
+    pub fn get(&self, id: EntityId) -> Option<&dyn Entity> {
+        self.entities.get(id)
+    }
+
+    pub fn get_mut(&mut self, id: EntityId) -> Option<&mut (dyn Entity + 'static)> {
+        self.entities.get_mut(id)
+    }
+
+    pub fn contains(&self, id: EntityId) -> bool {
+        self.entities.contains(id)
+    }
+
     pub fn get_player_score(&self, player_id: u32) -> u32 {
-        let mut score = 0;
-        for player in self.players.lock().unwrap().iter() {
-            if player.lock().unwrap().get_id() == player_id {
-                score = player.lock().unwrap().score;
+        self.get_player(player_id)
+            .map(|player| player.score)
+            .unwrap_or(0)
+    }
+
+    pub fn get_player_ids(&self) -> Vec<u32> {
+        self.entities
+            .iter_as::<Player>()
+            .map(|(_, player)| player.player_id)
+            .collect()
+    }
+
+    pub fn get_player(&self, player_id: u32) -> Option<&Player> {
+        self.entities
+            .iter_as::<Player>()
+            .find(|(_, player)| player.player_id == player_id)
+            .map(|(_, player)| player)
+    }
+
+    pub fn get_player_mut(&mut self, player_id: u32) -> Option<&mut Player> {
+        self.entities
+            .iter_as_mut::<Player>()
+            .find(|(_, player)| player.player_id == player_id)
+            .map(|(_, player)| player)
+    }
+
+    pub fn get_player_position(&self, player_id: u32) -> Option<(f32, f32)> {
+        self.get_player(player_id)
+            .map(|player| player.get_position())
+    }
+
+    /// Entity id of the player, `None` if there is no such player
+    pub fn find_player(&self, player_id: u32) -> Option<EntityId> {
+        self.get_player(player_id).map(|player| player.get_id())
+    }
+
+    pub fn iter_players(&self) -> impl Iterator<Item = (EntityId, &Player)> {
+        self.entities.iter_as::<Player>()
+    }
+
+    /// Ids of the entities of the collidable type, in the store order
+    pub fn find_by_type(&self, collidable_type: CollidableType) -> Vec<EntityId> {
+        self.entities
+            .iter()
+            .filter(|(_, entity)| entity.get_collidable_type() == collidable_type)
+            .map(|(id, _)| id)
+            .collect()
+    }
+
+    /// Move the entity without sweeping or interpolating from the old
+    /// position. `false` if it is already removed.
+    pub fn set_position(&mut self, id: EntityId, position_xy: (f32, f32)) -> bool {
+        let is_static = match self.entities.get_mut(id) {
+            Some(entity) => {
+                entity.set_position(position_xy);
+                entity.get_mut_object_attr().store_previous_position();
+                entity.get_body_type() == BodyType::Static
             }
+            None => return false,
+        };
+        if is_static {
+            self.entities.invalidate_static();
         }
-        score
+        true
     }
 
-    pub fn add_player_object(&mut self, player: Arc<Mutex<Player>>) {
-        self.add_object(player.clone());
-        self.players.lock().unwrap().push(player.clone());
+    /// Resize the bounding box keeping the top left corner, the collision
+    /// shape is scaled with it. `false` if it is already removed, panics if
+    /// the size is not positive.
+    pub fn set_size(&mut self, id: EntityId, size_wh: (f32, f32)) -> bool {
+        assert!(
+            size_wh.0 > 0.0 && size_wh.1 > 0.0 && size_wh.0.is_finite() && size_wh.1.is_finite(),
+            "invalid size {:?}",
+            size_wh
+        );
+        let is_static = match self.entities.get_mut(id) {
+            Some(entity) => {
+                let object_attr = entity.get_mut_object_attr();
+                let old_size = (object_attr.width, object_attr.height);
+                object_attr.width = size_wh.0;
+                object_attr.height = size_wh.1;
+                // An object without size has nothing to scale from
+                let scale = |new: f32, old: f32| if old > 0.0 { new / old } else { 1.0 };
+                let scale_xy = (scale(size_wh.0, old_size.0), scale(size_wh.1, old_size.1));
+                let collidable_attr = entity.borrow_mut_collidable_attributes();
+                let shape = collidable_attr.get_shape().scaled(scale_xy);
+                collidable_attr.set_shape(shape, size_wh);
+                entity.get_body_type() == BodyType::Static
+            }
+            None => return false,
+        };
+        if is_static {
+            self.entities.invalidate_static();
+        }
+        true
     }
 
-    pub fn add_collectable_object(&mut self, collectable: Arc<Mutex<Collectable>>) {
-        self.add_object(collectable.clone());
-        self.collectables.push(collectable.clone());
+    pub fn get_size(&self, id: EntityId) -> Option<(f32, f32)> {
+        self.entities
+            .get(id)
+            .map(|entity| (entity.get_width(), entity.get_height()))
     }
 
-    pub fn cleanup_objects(&mut self) {
-        //Get IDs of objects to remove
-        let mut ids_to_remove = Vec::new();
-        for obj in &self.objects {
-            if obj
-                .lock()
-                .unwrap()
-                .get_object_attr()
-                .is_deletion_requested()
-            {
-                ids_to_remove.push(obj.lock().unwrap().get_id());
+    /// Remove the entity at the next cleanup, the systems still see it
+    /// until then. `false` if it is already removed.
+    pub fn despawn(&mut self, id: EntityId) -> bool {
+        match self.entities.get_mut(id) {
+            Some(entity) => {
+                entity.get_mut_object_attr().request_deletion();
+                true
             }
+            None => false,
         }
+    }
 
-        //Remove objects from the list
-        self.objects.retain(|obj| {
-            let id = obj.lock().unwrap().get_id();
-            !ids_to_remove.contains(&id)
-        });
-        self.players.lock().unwrap().retain(|player| {
-            let id = player.lock().unwrap().get_id();
-            !ids_to_remove.contains(&id)
-        });
-        self.collectables.retain(|collectable| {
-            let id = collectable.lock().unwrap().get_id();
-            !ids_to_remove.contains(&id)
-        });
-    }
-
-    fn get_new_object_id(&mut self) -> u32 {
-        let id = self.next_object_id;
-        self.next_object_id += 1;
-        id
+    /// Remove the objects that requested their deletion, returns their ids
+    /// and types for the systems to forget them
+    pub fn cleanup_objects(&mut self) -> Vec<(EntityId, CollidableType)> {
+        let ids_to_remove: Vec<EntityId> = self
+            .entities
+            .iter()
+            .filter(|(_, entity)| entity.get_object_attr().is_deletion_requested())
+            .map(|(id, _)| id)
+            .collect();
+        ids_to_remove
+            .into_iter()
+            .filter_map(|id| Some((id, self.remove(id)?)))
+            .collect()
     }
 
-    pub fn add_object<T: GameObject + 'static>(&mut self, obj: Arc<Mutex<T>>) {
-        let id = self.get_new_object_id();
-        obj.lock().unwrap().set_id(id);
-        log::debug!("Added {} id: {}", obj.lock().unwrap().get_name(), id);
-        self.objects.push(obj);
+    /// Remove the entity right away, calling its `deletion_callback`. The
+    /// systems are not told, see `Universe::remove`.
+    pub(crate) fn remove(&mut self, id: EntityId) -> Option<CollidableType> {
+        let entity = self.entities.remove(id)?;
+        entity.deletion_callback();
+        log::debug!("Removed {} id: {}", entity.get_name(), id);
+        Some(entity.get_collidable_type())
     }
 
-    pub fn update(&self, delta_time: f32) {
-        for player in self.players.lock().unwrap().iter() {
-            player.lock().unwrap().update_position(delta_time);
-        }
+    pub fn add_object<T: Entity>(&mut self, obj: T) -> EntityId {
+        let name = obj.get_name();
+        let id = self.entities.insert(Box::new(obj));
+        log::debug!("Added {} id: {}", name, id);
+        id
     }
 
-    pub fn draw(&self, scale: f32) {
+    pub fn update(&mut self, delta_time: f32) {
+        for (_, player) in self.entities.iter_as_mut::<Player>() {
+            player.update_position(delta_time);
+        }
+    }
 
-        for obj in &self.objects {
-            let o = obj.lock().unwrap();
-            if let Some(_) = obj.as_any().downcast_ref::<Player>() {
-                continue;
+    /// The players are drawn on top
+    pub fn draw(&self, scale: f32, alpha: f32) {
+        let is_player = |entity: &dyn Entity| {
+            let entity: &dyn std::any::Any = entity;
+            entity.is::<Player>()
+        };
+        for (_, entity) in self.entities.iter() {
+            if !is_player(entity) {
+                entity.draw(scale, alpha);
             }
-            o.draw(scale);
         }
-        for player in self.players.lock().unwrap().iter() {
-            player.lock().unwrap().draw(scale);
+        for (_, player) in self.entities.iter_as::<Player>() {
+            player.draw(scale, alpha);
         }
     }
 }
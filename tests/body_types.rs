@@ -0,0 +1,48 @@
+// *************************************************************************
+//
+// Copyright (c) 2025 Andrei Gramakov. All rights reserved.
+//
+// This file is licensed under the terms of the MIT license.
+// For a copy, see: https://opensource.org/licenses/MIT
+//
+// site:    https://agramakov.me
+// e-mail:  mail@agramakov.me
+//
+// *************************************************************************
+mod common;
+
+use cbb_2d::{
+    entity::EntityStore,
+    objects::{Collectable, Player, Wall},
+    sys_collision::SysCollision,
+    sys_event::SysEvent,
+};
+use common::Probe;
+use macroquad::color::RED;
+use std::sync::{Arc, Mutex};
+
+#[test]
+fn only_pairs_with_a_dynamic_body_are_checked() {
+    let mut sys_collision = SysCollision::new(Arc::new(Mutex::new(SysEvent::new())));
+    let mut entities = EntityStore::new();
+    // Two walls forming a corner, each one only slightly in the way of the
+    // player, so pushing the player out of one keeps it in the other
+    let probes = [
+        Probe::insert(&mut entities, Wall::new((90.0, 100.0), (22.0, 60.0))),
+        Probe::insert(&mut entities, Wall::new((90.0, 148.0), (80.0, 22.0))),
+        Probe::insert(&mut entities, Collectable::new(130.0, 130.0)),
+        Probe::insert(&mut entities, Collectable::new(132.0, 132.0)),
+    ];
+    sys_collision.process_collisions(&mut entities);
+    for probe in probes {
+        assert_eq!(Probe::get_overlaps(&entities, probe), 0);
+    }
+
+    // A player overlapping all of them is checked against every one
+    let player = Probe::insert(
+        &mut entities,
+        Player::new(0, (110.0, 110.0), (40.0, 40.0), 1.0, 0.2, RED),
+    );
+    sys_collision.process_collisions(&mut entities);
+    assert_eq!(Probe::get_overlaps(&entities, player), 4);
+}
@@ -0,0 +1,47 @@
+// *************************************************************************
+//
+// Copyright (c) 2025 Andrei Gramakov. All rights reserved.
+//
+// This file is licensed under the terms of the MIT license.
+// For a copy, see: https://opensource.org/licenses/MIT
+//
+// site:    https://agramakov.me
+// e-mail:  mail@agramakov.me
+//
+// *************************************************************************
+use cbb_2d::sys_collision::CollidableType;
+use std::thread;
+
+#[test]
+fn built_in_names_are_found() {
+    assert_eq!(CollidableType::find("player"), Some(CollidableType::PLAYER));
+    assert_eq!(CollidableType::register("zone"), CollidableType::ZONE);
+    assert_eq!(CollidableType::PLAYER.get_name(), "player");
+}
+
+#[test]
+fn registered_types_round_trip() {
+    let enemy = CollidableType::register("registry_test_enemy");
+    assert_eq!(CollidableType::find("registry_test_enemy"), Some(enemy));
+    assert_eq!(CollidableType::from_id(enemy.get_id()), enemy);
+    assert_eq!(enemy.get_name(), "registry_test_enemy");
+    assert_eq!(CollidableType::register("registry_test_enemy"), enemy);
+}
+
+#[test]
+fn unknown_ids_are_named_by_the_id() {
+    assert_eq!(CollidableType::from_id(100).get_name(), "unknown(100)");
+    assert_eq!(CollidableType::find("registry_test_missing"), None);
+}
+
+#[test]
+fn concurrent_registrations_give_one_type() {
+    let types: Vec<CollidableType> = (0..8)
+        .map(|_| thread::spawn(|| CollidableType::register("registry_test_bullet")))
+        .collect::<Vec<_>>()
+        .into_iter()
+        .map(|handle| handle.join().unwrap())
+        .collect();
+    assert!(types.iter().all(|t| *t == types[0]));
+    assert_eq!(CollidableType::find("registry_test_bullet"), Some(types[0]));
+}
@@ -0,0 +1,429 @@
+// *************************************************************************
+//
+// Copyright (c) 2025 Andrei Gramakov. All rights reserved.
+//
+// This file is licensed under the terms of the MIT license.
+// For a copy, see: https://opensource.org/licenses/MIT
+//
+// site:    https://agramakov.me
+// e-mail:  mail@agramakov.me
+//
+// *************************************************************************
+use cbb_2d::{
+    entity::EntityId,
+    objects::CollisionLayer,
+    sys_collision::{
+        CollidableType, CollisionFilter, CollisionShape, PhysicsMaterial, QueryFilter,
+    },
+    sys_event::{Event, Subscriber},
+    universe::Universe,
+};
+use macroquad::color::{GREEN, RED};
+use std::sync::{Arc, Mutex};
+
+/// Counts the collision events seen on the bus
+#[derive(Default)]
+struct CollisionCounter {
+    started: usize,
+    ended: usize,
+    entered: usize,
+    exited: usize,
+}
+
+impl Subscriber for CollisionCounter {
+    fn handle_busevent(&mut self, event: &Event) {
+        match event {
+            Event::CollisionStarted { .. } => self.started += 1,
+            Event::CollisionEnded { .. } => self.ended += 1,
+            Event::SensorEntered { .. } => self.entered += 1,
+            Event::SensorExited { .. } => self.exited += 1,
+            _ => {}
+        }
+    }
+}
+
+fn new_universe() -> Universe {
+    let mut universe = Universe::new_headless((800.0, 600.0));
+    universe.add_player(0, (100.0, 100.0), (30.0, 30.0), 1.0, 0.2, RED);
+    universe
+}
+
+/// Push the player to the right for five seconds
+fn push_right(universe: &mut Universe) -> (f32, f32) {
+    universe.publish_event(Event::MovePlayer {
+        id: 0,
+        fx: 100.0,
+        fy: 0.0,
+    });
+    for _ in 0..300 {
+        universe.step(1.0 / 60.0);
+    }
+    universe.get_player_position(0).unwrap()
+}
+
+#[test]
+fn cell_size_can_be_changed() {
+    let mut universe = new_universe();
+    universe.set_collision_cell_size(200.0);
+    universe.step(1.0 / 60.0);
+    assert_eq!(universe.get_player_position(0), Some((100.0, 100.0)));
+}
+
+#[test]
+#[should_panic(expected = "invalid cell size")]
+fn rejects_a_zero_cell_size() {
+    new_universe().set_collision_cell_size(0.0);
+}
+
+#[test]
+#[should_panic(expected = "invalid cell size")]
+fn rejects_a_negative_cell_size() {
+    new_universe().set_collision_cell_size(-10.0);
+}
+
+#[test]
+fn objects_spanning_many_cells_still_collide() {
+    for cell_size in [5.0, 64.0, 1000.0] {
+        let mut universe = new_universe();
+        universe.set_collision_cell_size(cell_size);
+        universe.add_wall((200.0, 0.0), (20.0, 600.0));
+        let (x, _) = push_right(&mut universe);
+        assert!(x <= 170.1, "cell size {}: went through at {}", cell_size, x);
+    }
+}
+
+#[test]
+fn the_player_slides_over_a_round_wall() {
+    // Only the corner of the bounding box is in the way of the player
+    let mut universe = new_universe();
+    universe.add_shaped_wall(
+        (200.0, 120.0),
+        (80.0, 80.0),
+        CollisionShape::Ball { radius: 40.0 },
+    );
+    let (x, y) = push_right(&mut universe);
+    assert!(x > 280.0, "the player got stuck at {:?}", (x, y));
+
+    let mut universe = new_universe();
+    universe.add_wall((200.0, 120.0), (80.0, 80.0));
+    let (x, _) = push_right(&mut universe);
+    assert!(x <= 170.1, "the player went through the wall: {}", x);
+}
+
+#[test]
+fn a_touch_starts_and_ends_the_collision_once() {
+    // Without bouncing the player rests against the wall until it turns back
+    let mut universe = new_universe();
+    let wall = universe.add_wall((200.0, 0.0), (20.0, 600.0));
+    let no_bounce = PhysicsMaterial::new(0.0, 0.0);
+    universe.set_physics_material(wall, no_bounce);
+    universe.set_player_physics_material(0, no_bounce);
+    let counter = Arc::new(Mutex::new(CollisionCounter::default()));
+    universe.subscribe(counter.clone());
+
+    push_right(&mut universe);
+    assert_eq!(counter.lock().unwrap().started, 1);
+    assert_eq!(counter.lock().unwrap().ended, 0);
+
+    universe.publish_event(Event::MovePlayer {
+        id: 0,
+        fx: -100.0,
+        fy: 0.0,
+    });
+    for _ in 0..120 {
+        universe.step(1.0 / 60.0);
+    }
+    assert!(universe.get_player_position(0).unwrap().0 < 150.0);
+    let counter = counter.lock().unwrap();
+    assert_eq!((counter.started, counter.ended), (1, 1));
+}
+
+/// Run the player towards a wall it does not interact with
+fn assert_passes_the_wall(mut universe: Universe) {
+    let counter = Arc::new(Mutex::new(CollisionCounter::default()));
+    universe.subscribe(counter.clone());
+    let (x, _) = push_right(&mut universe);
+    assert!(x > 230.0, "stopped at {}", x);
+    let counter = counter.lock().unwrap();
+    assert_eq!((counter.started, counter.ended), (0, 0));
+}
+
+#[test]
+fn a_mask_without_the_wall_layer_ignores_walls() {
+    let mut universe = new_universe();
+    universe.add_wall((200.0, 0.0), (20.0, 600.0));
+    let filter = CollisionFilter::new(
+        CollisionLayer::PLAYER,
+        CollisionFilter::ALL & !CollisionLayer::WALL,
+    );
+    universe.set_player_collision_filter(0, filter);
+    assert_passes_the_wall(universe);
+}
+
+#[test]
+fn a_rejected_pair_does_not_collide() {
+    let mut universe = new_universe();
+    let wall_id = universe.add_wall((200.0, 0.0), (20.0, 600.0));
+    universe.set_pair_filter(Some(Box::new(move |a, b| {
+        a.borrow_base_object().id != wall_id && b.borrow_base_object().id != wall_id
+    })));
+    assert_passes_the_wall(universe);
+}
+
+#[test]
+fn player_moves_through_a_zone_and_pushes_into_a_wall() {
+    let mut universe = new_universe();
+    let zone = universe.add_zone(
+        (150.0, 80.0),
+        (50.0, 70.0),
+        CollisionShape::Rectangle,
+        GREEN,
+    );
+    universe.add_wall((250.0, 50.0), (20.0, 130.0));
+    let counter = Arc::new(Mutex::new(CollisionCounter::default()));
+    universe.subscribe(counter.clone());
+    universe.publish_event(Event::MovePlayer {
+        id: 0,
+        fx: 100.0,
+        fy: 0.0,
+    });
+    let mut entered = false;
+    for _ in 0..300 {
+        universe.step(1.0 / 60.0);
+        entered |= !universe.get_sensor_contacts(zone).is_empty();
+    }
+    assert!(entered, "the player never entered the zone");
+    let (x, _) = universe.get_player_position(0).unwrap();
+    assert!(
+        x > 200.0 && x <= 220.1,
+        "the player is not at the wall: {}",
+        x
+    );
+    let counter = counter.lock().unwrap();
+    assert_eq!((counter.entered, counter.exited), (1, 1));
+}
+
+/// Push a light player towards a wall of 2 px, returns the final position
+/// and the longest move of a single tick
+fn shoot_at_a_thin_wall(is_ccd_enabled: bool) -> (f32, f32) {
+    let mut universe = Universe::new_headless((800.0, 600.0));
+    universe.add_player(0, (100.0, 100.0), (30.0, 30.0), 0.05, 0.0, RED);
+    universe.set_player_ccd_enabled(0, is_ccd_enabled);
+    universe.add_wall((400.0, 0.0), (2.0, 600.0));
+    universe.publish_event(Event::MovePlayer {
+        id: 0,
+        fx: 100.0,
+        fy: 0.0,
+    });
+    let mut longest_move: f32 = 0.0;
+    let mut x = 100.0;
+    for _ in 0..60 {
+        universe.step(1.0 / 60.0);
+        let (new_x, _) = universe.get_player_position(0).unwrap();
+        longest_move = longest_move.max((new_x - x).abs());
+        x = new_x;
+    }
+    (x, longest_move)
+}
+
+#[test]
+fn ccd_stops_a_fast_player_at_a_thin_wall() {
+    let (x, longest_move) = shoot_at_a_thin_wall(true);
+    assert!(longest_move > 2.0, "too slow: {}", longest_move);
+    assert!(x <= 370.1, "went through to {}", x);
+
+    let (x, _) = shoot_at_a_thin_wall(false);
+    assert!(x > 402.0, "stopped at {} without ccd", x);
+}
+
+/// Player at (100, 100), a zone and two walls to its right, the static grid
+/// is baked by the first step
+fn new_query_universe() -> (Universe, EntityId, EntityId, EntityId) {
+    let mut universe = new_universe();
+    let zone = universe.add_zone(
+        (200.0, 100.0),
+        (30.0, 30.0),
+        CollisionShape::Rectangle,
+        GREEN,
+    );
+    let near_wall = universe.add_wall((300.0, 50.0), (20.0, 100.0));
+    let far_wall = universe.add_wall((500.0, 50.0), (20.0, 100.0));
+    universe.step(1.0 / 60.0);
+    (universe, zone, near_wall, far_wall)
+}
+
+#[test]
+fn point_query_finds_the_object_under_the_point() {
+    let (universe, zone, near_wall, _) = new_query_universe();
+    let filter = QueryFilter::default();
+    assert_eq!(
+        universe.query_point((310.0, 60.0), &filter),
+        vec![near_wall]
+    );
+    assert_eq!(universe.query_point((215.0, 115.0), &filter), vec![zone]);
+    assert!(universe
+        .query_point((215.0, 115.0), &QueryFilter::solid(u32::MAX))
+        .is_empty());
+    assert!(universe.query_point((400.0, 60.0), &filter).is_empty());
+}
+
+#[test]
+fn radius_query_returns_the_nearest_first() {
+    let (universe, zone, near_wall, far_wall) = new_query_universe();
+    let filter = QueryFilter::default();
+    assert_eq!(
+        universe.query_radius((260.0, 115.0), 45.0, &filter),
+        vec![zone, near_wall]
+    );
+    assert!(universe
+        .query_radius((400.0, 300.0), 50.0, &filter)
+        .is_empty());
+    let everything = universe.query_radius((400.0, 100.0), f32::INFINITY, &filter);
+    assert_eq!(everything.len(), 4);
+    assert!(everything.contains(&far_wall));
+}
+
+#[test]
+fn ray_hits_the_first_solid_object() {
+    let (universe, _, near_wall, far_wall) = new_query_universe();
+    let filter = QueryFilter::solid(u32::MAX);
+    let hit = universe
+        .cast_ray((140.0, 100.0), (1.0, 0.0), 1000.0, &filter)
+        .unwrap();
+    assert_eq!(hit.object_id, near_wall);
+    assert_eq!(hit.object_type, CollidableType::SOLID_OBSTACLE);
+    assert!((hit.distance - 160.0).abs() < 1e-3, "{}", hit.distance);
+    assert_eq!(hit.normal_xy, (-1.0, 0.0));
+
+    let mut filter = filter;
+    filter.set_excluded_id(Some(near_wall));
+    let hit = universe.cast_ray((140.0, 100.0), (1.0, 0.0), 1000.0, &filter);
+    assert_eq!(hit.map(|hit| hit.object_id), Some(far_wall));
+    assert!(universe
+        .cast_ray((140.0, 100.0), (1.0, 0.0), 100.0, &filter)
+        .is_none());
+}
+
+#[test]
+fn unlimited_rays_end() {
+    let (universe, _, near_wall, _) = new_query_universe();
+    let filter = QueryFilter::solid(u32::MAX);
+    for distance in [f32::INFINITY, f32::MAX, 1e30] {
+        let hit = universe.cast_ray((140.0, 100.0), (1.0, 0.0), distance, &filter);
+        assert_eq!(hit.map(|hit| hit.object_id), Some(near_wall));
+        let miss = universe.cast_ray((140.0, 100.0), (-1.0, -1.0), distance, &filter);
+        assert!(miss.is_none());
+    }
+}
+
+#[test]
+fn resizing_scales_the_shape() {
+    let mut universe = new_universe();
+    let ball = universe.add_shaped_wall(
+        (400.0, 300.0),
+        (20.0, 20.0),
+        CollisionShape::Ball { radius: 10.0 },
+    );
+    universe.step(1.0 / 60.0);
+    let filter = QueryFilter::default();
+    assert!(universe.query_point((435.0, 335.0), &filter).is_empty());
+
+    assert!(universe.set_size(ball, (80.0, 80.0)));
+    universe.step(1.0 / 60.0);
+    assert_eq!(universe.get_size(ball), Some((80.0, 80.0)));
+    assert_eq!(universe.query_point((435.0, 335.0), &filter), vec![ball]);
+    // Still a ball, the corner of the box is outside
+    assert!(universe.query_point((402.0, 302.0), &filter).is_empty());
+}
+
+#[test]
+#[should_panic(expected = "invalid size")]
+fn rejects_a_zero_size() {
+    let mut universe = new_universe();
+    let wall = universe.add_wall((400.0, 300.0), (20.0, 20.0));
+    universe.set_size(wall, (0.0, 20.0));
+}
+
+#[test]
+#[should_panic(expected = "invalid size")]
+fn rejects_a_negative_size() {
+    let mut universe = new_universe();
+    let wall = universe.add_wall((400.0, 300.0), (20.0, 20.0));
+    universe.set_size(wall, (20.0, -5.0));
+}
+
+#[test]
+fn scaled_shapes_keep_their_kind() {
+    let shape = CollisionShape::Compound {
+        parts: vec![
+            (
+                (10.0, -5.0),
+                0.0,
+                CollisionShape::Cuboid {
+                    half_extents_wh: (4.0, 2.0),
+                },
+            ),
+            (
+                (0.0, 0.0),
+                0.0,
+                CollisionShape::ConvexPolygon {
+                    points: vec![(0.0, 0.0), (1.0, 0.0), (0.0, 1.0)],
+                },
+            ),
+            (
+                (0.0, 0.0),
+                0.0,
+                CollisionShape::Capsule {
+                    half_height: 5.0,
+                    radius: 2.0,
+                },
+            ),
+        ],
+    };
+    let expected = CollisionShape::Compound {
+        parts: vec![
+            (
+                (20.0, -15.0),
+                0.0,
+                CollisionShape::Cuboid {
+                    half_extents_wh: (8.0, 6.0),
+                },
+            ),
+            (
+                (0.0, 0.0),
+                0.0,
+                CollisionShape::ConvexPolygon {
+                    points: vec![(0.0, 0.0), (2.0, 0.0), (0.0, 3.0)],
+                },
+            ),
+            (
+                (0.0, 0.0),
+                0.0,
+                CollisionShape::Capsule {
+                    half_height: 17.0,
+                    radius: 4.0,
+                },
+            ),
+        ],
+    };
+    assert_eq!(
+        format!("{:?}", shape.scaled((2.0, 3.0))),
+        format!("{:?}", expected)
+    );
+}
+
+#[test]
+fn rotated_parts_are_stretched_along_the_object_axes() {
+    // Standing upright after the rotation: 2 wide and 4 tall
+    let shape = CollisionShape::rotated_rectangle((4.0, 2.0), std::f32::consts::FRAC_PI_2);
+    let CollisionShape::Compound { parts } = shape.scaled((2.0, 1.0)) else {
+        panic!("not a compound");
+    };
+    let [(_, angle, CollisionShape::ConvexPolygon { points })] = parts.as_slice() else {
+        panic!("not a polygon: {:?}", parts);
+    };
+    assert_eq!(*angle, 0.0);
+    let extent = |axis: fn(&(f32, f32)) -> f32| points.iter().map(axis).fold(0.0, f32::max);
+    assert!((extent(|p| p.0.abs()) - 2.0).abs() < 1e-4, "{:?}", points);
+    assert!((extent(|p| p.1.abs()) - 2.0).abs() < 1e-4, "{:?}", points);
+}
@@ -0,0 +1,160 @@
+// *************************************************************************
+//
+// Copyright (c) 2025 Andrei Gramakov. All rights reserved.
+//
+// This file is licensed under the terms of the MIT license.
+// For a copy, see: https://opensource.org/licenses/MIT
+//
+// site:    https://agramakov.me
+// e-mail:  mail@agramakov.me
+//
+// *************************************************************************
+// Helpers shared by the tests, not every test uses all of them
+#![allow(dead_code)]
+
+use cbb_2d::{
+    base::{DynamicAttributes, GameObject, GameObjectAttributes},
+    entity::{Entity, EntityId, EntityStore},
+    sys_collision::{Collidable, CollidableAttributes, CollidableType},
+    sys_event::Subscriber,
+};
+use std::sync::{
+    atomic::{AtomicBool, Ordering},
+    Arc,
+};
+
+/// Wraps an object and notes what the systems did with it
+pub struct Probe {
+    inner: Box<dyn Entity>,
+    /// Overlaps the narrow phase found for the object
+    pub overlaps: usize,
+    /// Set by `deletion_callback`
+    pub is_deleted: Arc<AtomicBool>,
+}
+
+impl Probe {
+    pub fn new(inner: impl Entity) -> Self {
+        Self {
+            inner: Box::new(inner),
+            overlaps: 0,
+            is_deleted: Arc::new(AtomicBool::new(false)),
+        }
+    }
+
+    pub fn insert(entities: &mut EntityStore, inner: impl Entity) -> EntityId {
+        entities.insert(Box::new(Self::new(inner)))
+    }
+
+    pub fn get_overlaps(entities: &EntityStore, id: EntityId) -> usize {
+        entities.get_as::<Probe>(id).unwrap().overlaps
+    }
+}
+
+impl GameObject for Probe {
+    fn get_mut_object_attr(&mut self) -> &mut GameObjectAttributes {
+        self.inner.get_mut_object_attr()
+    }
+
+    fn get_object_attr(&self) -> &GameObjectAttributes {
+        self.inner.get_object_attr()
+    }
+
+    fn get_position(&self) -> (f32, f32) {
+        self.inner.get_position()
+    }
+
+    fn set_position(&mut self, position_xy: (f32, f32)) {
+        self.inner.set_position(position_xy);
+    }
+
+    fn get_id(&self) -> EntityId {
+        self.inner.get_id()
+    }
+
+    fn set_id(&mut self, id: EntityId) {
+        self.inner.set_id(id);
+    }
+
+    fn get_name(&self) -> String {
+        self.inner.get_name()
+    }
+
+    fn deletion_callback(&self) {
+        self.is_deleted.store(true, Ordering::SeqCst);
+        self.inner.deletion_callback();
+    }
+
+    fn draw(&self, scale: f32, alpha: f32) {
+        self.inner.draw(scale, alpha);
+    }
+}
+
+impl Entity for Probe {
+    fn as_subscriber_mut(&mut self) -> Option<&mut dyn Subscriber> {
+        self.inner.as_subscriber_mut()
+    }
+}
+
+impl Collidable for Probe {
+    fn get_x(&self) -> f32 {
+        self.inner.get_x()
+    }
+
+    fn get_y(&self) -> f32 {
+        self.inner.get_y()
+    }
+
+    fn get_width(&self) -> f32 {
+        self.inner.get_width()
+    }
+
+    fn get_height(&self) -> f32 {
+        self.inner.get_height()
+    }
+
+    fn borrow_mut_dynamic_attributes(&mut self) -> Option<&mut DynamicAttributes> {
+        self.inner.borrow_mut_dynamic_attributes()
+    }
+
+    fn borrow_mut_base_object(&mut self) -> &mut GameObjectAttributes {
+        self.inner.borrow_mut_base_object()
+    }
+
+    fn borrow_base_object(&self) -> &GameObjectAttributes {
+        self.inner.borrow_base_object()
+    }
+
+    fn borrow_dynamic_attributes(&self) -> Option<&DynamicAttributes> {
+        self.inner.borrow_dynamic_attributes()
+    }
+
+    fn borrow_collidable_attributes(&self) -> &CollidableAttributes {
+        self.inner.borrow_collidable_attributes()
+    }
+
+    fn borrow_mut_collidable_attributes(&mut self) -> &mut CollidableAttributes {
+        self.inner.borrow_mut_collidable_attributes()
+    }
+
+    fn get_collidable_type(&self) -> CollidableType {
+        self.inner.get_collidable_type()
+    }
+
+    fn process_collision(&mut self, other: &dyn Collidable) {
+        self.overlaps += 1;
+        self.inner.process_collision(other);
+    }
+
+    fn on_sensor_enter(&mut self, other: &dyn Collidable) {
+        self.overlaps += 1;
+        self.inner.on_sensor_enter(other);
+    }
+
+    fn on_sensor_stay(&mut self, other: &dyn Collidable) {
+        self.inner.on_sensor_stay(other);
+    }
+
+    fn on_sensor_exit(&mut self, other_id: EntityId, other_type: CollidableType) {
+        self.inner.on_sensor_exit(other_id, other_type);
+    }
+}
@@ -0,0 +1,78 @@
+// *************************************************************************
+//
+// Copyright (c) 2025 Andrei Gramakov. All rights reserved.
+//
+// This file is licensed under the terms of the MIT license.
+// For a copy, see: https://opensource.org/licenses/MIT
+//
+// site:    https://agramakov.me
+// e-mail:  mail@agramakov.me
+//
+// *************************************************************************
+use cbb_2d::{
+    base::Dynamic,
+    entity::EntityStore,
+    objects::{Player, Wall},
+    sys_collision::{Collidable, PhysicsMaterial, SysCollision},
+    sys_event::SysEvent,
+};
+use macroquad::color::RED;
+use std::sync::{Arc, Mutex};
+
+fn new_player(x: f32, mass: f32, velocity_x: f32) -> Box<Player> {
+    let mut player = Player::new(0, (x, 100.0), (30.0, 30.0), mass, 0.2, RED);
+    player.set_velocity((velocity_x, 0.0));
+    Box::new(player)
+}
+
+/// A moving player runs into a resting one, returns how far the resting one
+/// slides in a second
+fn push_distance(pusher_mass: f32, pushed_mass: f32) -> f32 {
+    let mut sys_collision = SysCollision::new(Arc::new(Mutex::new(SysEvent::new())));
+    let mut entities = EntityStore::new();
+    entities.insert(new_player(100.0, pusher_mass, 200.0));
+    let pushed = entities.insert(new_player(125.0, pushed_mass, 0.0));
+    sys_collision.process_collisions(&mut entities);
+
+    let pushed = entities.get_as_mut::<Player>(pushed).unwrap();
+    for _ in 0..60 {
+        pushed.update_position(1.0 / 60.0);
+    }
+    pushed.get_x() - 125.0
+}
+
+#[test]
+fn a_heavy_body_pushes_a_light_one_further() {
+    let heavy_pushes_light = push_distance(10.0, 1.0);
+    let light_pushes_heavy = push_distance(1.0, 10.0);
+    assert!(
+        heavy_pushes_light > 2.0 * light_pushes_heavy,
+        "{} vs {}",
+        heavy_pushes_light,
+        light_pushes_heavy
+    );
+}
+
+/// Velocity of a player after it hits a wall at 100 px/s
+fn bounce_velocity(restitution: f32) -> f32 {
+    let mut sys_collision = SysCollision::new(Arc::new(Mutex::new(SysEvent::new())));
+    let mut entities = EntityStore::new();
+    let material = PhysicsMaterial::new(restitution, 0.0);
+    let mut player = new_player(100.0, 1.0, 100.0);
+    player.set_physics_material(material);
+    let player = entities.insert(player);
+    let mut wall = Wall::new((125.0, 50.0), (20.0, 130.0));
+    wall.set_physics_material(material);
+    entities.insert(Box::new(wall));
+    sys_collision.process_collisions(&mut entities);
+
+    let player = entities.get_as::<Player>(player).unwrap();
+    player.borrow_dynamic_attributes().unwrap().vel_x
+}
+
+#[test]
+fn restitution_decides_the_separation_velocity() {
+    assert!(bounce_velocity(0.0).abs() < 1e-3);
+    assert!((bounce_velocity(1.0) + 100.0).abs() < 1e-3);
+    assert!((bounce_velocity(0.5) + 50.0).abs() < 1e-3);
+}
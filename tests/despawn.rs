@@ -0,0 +1,87 @@
+// *************************************************************************
+//
+// Copyright (c) 2025 Andrei Gramakov. All rights reserved.
+//
+// This file is licensed under the terms of the MIT license.
+// For a copy, see: https://opensource.org/licenses/MIT
+//
+// site:    https://agramakov.me
+// e-mail:  mail@agramakov.me
+//
+// *************************************************************************
+mod common;
+
+use cbb_2d::{
+    entity::EntityId,
+    objects::Wall,
+    sys_collision::QueryFilter,
+    sys_event::{Event, Subscriber},
+    universe::Universe,
+    world::World,
+};
+use common::Probe;
+use std::sync::{atomic::Ordering, Arc, Mutex};
+
+/// Counts the `EntityDespawned` events of one entity
+struct DespawnCounter {
+    id: EntityId,
+    count: usize,
+}
+
+impl Subscriber for DespawnCounter {
+    fn handle_busevent(&mut self, event: &Event) {
+        if let Event::EntityDespawned { id, .. } = event {
+            if *id == self.id {
+                self.count += 1;
+            }
+        }
+    }
+}
+
+#[test]
+fn cleanup_calls_the_deletion_callback() {
+    let mut world = World::new();
+    let probe = Probe::new(Wall::new((0.0, 0.0), (10.0, 10.0)));
+    let is_deleted = probe.is_deleted.clone();
+    let id = world.add_object(probe);
+
+    assert!(world.despawn(id));
+    assert!(!is_deleted.load(Ordering::SeqCst));
+    assert_eq!(world.cleanup_objects().len(), 1);
+    assert!(is_deleted.load(Ordering::SeqCst));
+    assert!(!world.despawn(id));
+}
+
+#[test]
+fn despawned_entity_stays_visible_until_the_cleanup() {
+    let mut universe = Universe::new_headless((800.0, 600.0));
+    let wall = universe.add_wall((300.0, 50.0), (20.0, 100.0));
+    universe.step(1.0 / 60.0);
+
+    let filter = QueryFilter::default();
+    assert!(universe.despawn(wall));
+    assert!(universe.contains(wall));
+    assert_eq!(universe.query_point((310.0, 60.0), &filter), vec![wall]);
+
+    universe.step(1.0 / 60.0);
+    assert!(!universe.contains(wall));
+    assert!(universe.query_point((310.0, 60.0), &filter).is_empty());
+    assert!(!universe.despawn(wall));
+}
+
+#[test]
+fn despawn_is_published_once() {
+    let mut universe = Universe::new_headless((800.0, 600.0));
+    let wall = universe.add_wall((300.0, 50.0), (20.0, 100.0));
+    let counter = Arc::new(Mutex::new(DespawnCounter { id: wall, count: 0 }));
+    universe.subscribe(counter.clone());
+
+    assert!(universe.despawn(wall));
+    assert!(universe.despawn(wall));
+    universe.step(1.0 / 60.0);
+    assert_eq!(counter.lock().unwrap().count, 0);
+    for _ in 0..3 {
+        universe.step(1.0 / 60.0);
+    }
+    assert_eq!(counter.lock().unwrap().count, 1);
+}
@@ -0,0 +1,117 @@
+// *************************************************************************
+//
+// Copyright (c) 2025 Andrei Gramakov. All rights reserved.
+//
+// This file is licensed under the terms of the MIT license.
+// For a copy, see: https://opensource.org/licenses/MIT
+//
+// site:    https://agramakov.me
+// e-mail:  mail@agramakov.me
+//
+// *************************************************************************
+use cbb_2d::{
+    entity::EntityStore,
+    objects::{Player, Wall},
+    sys_collision::{CollidableType, QueryFilter},
+    universe::Universe,
+};
+use macroquad::color::RED;
+
+fn new_player(player_id: u32) -> Box<Player> {
+    Box::new(Player::new(
+        player_id,
+        (100.0, 100.0),
+        (30.0, 30.0),
+        1.0,
+        0.2,
+        RED,
+    ))
+}
+
+#[test]
+fn entities_are_found_by_their_type() {
+    let mut entities = EntityStore::new();
+    let wall = entities.insert(Box::new(Wall::new((0.0, 0.0), (10.0, 10.0))));
+    let first = entities.insert(new_player(1));
+    let second = entities.insert(new_player(2));
+    assert_eq!(entities.len(), 3);
+
+    let players: Vec<_> = entities
+        .iter_as::<Player>()
+        .map(|(id, player)| (id, player.player_id))
+        .collect();
+    assert_eq!(players, vec![(first, 1), (second, 2)]);
+    assert!(entities.get_as::<Wall>(wall).is_some());
+    assert!(entities.get_as::<Player>(wall).is_none());
+}
+
+#[test]
+fn removed_slots_are_reused_with_a_new_generation() {
+    let mut entities = EntityStore::new();
+    let first = entities.insert(new_player(1));
+    assert!(entities.remove(first).is_some());
+    assert!(entities.remove(first).is_none());
+    assert!(entities.is_empty());
+
+    let second = entities.insert(new_player(2));
+    assert_eq!(second.get_index(), first.get_index());
+    assert_ne!(second, first);
+    assert!(!entities.contains(first));
+    assert!(entities.get_as::<Player>(first).is_none());
+    assert_eq!(entities.get_as::<Player>(second).unwrap().player_id, 2);
+}
+
+#[test]
+fn stale_handles_do_not_reach_the_new_entity_of_the_slot() {
+    let mut universe = Universe::new_headless((800.0, 600.0));
+    let removed = universe.add_collectible((100.0, 100.0));
+    assert!(universe.remove(removed));
+
+    let new_id = universe.add_collectible((500.0, 500.0));
+    assert_eq!(new_id.get_index(), removed.get_index());
+    assert!(universe.get(removed).is_none());
+    assert!(universe.get_mut(removed).is_none());
+    assert!(!universe.contains(removed));
+    assert!(!universe.set_position(removed, (0.0, 0.0)));
+    assert!(!universe.set_size(removed, (1.0, 1.0)));
+    assert!(!universe.remove(removed));
+    assert_eq!(universe.get_position(new_id), Some((500.0, 500.0)));
+}
+
+#[test]
+fn entities_are_moved_resized_and_removed_by_id() {
+    let mut universe = Universe::new_headless((800.0, 600.0));
+    let wall = universe.add_wall((300.0, 50.0), (20.0, 100.0));
+    universe.step(1.0 / 60.0);
+    let filter = QueryFilter::default();
+
+    assert!(universe.set_position(wall, (500.0, 50.0)));
+    assert_eq!(universe.get_position(wall), Some((500.0, 50.0)));
+    assert!(universe.query_point((310.0, 60.0), &filter).is_empty());
+    assert_eq!(universe.query_point((510.0, 60.0), &filter), vec![wall]);
+
+    assert!(universe.set_size(wall, (20.0, 300.0)));
+    assert_eq!(universe.get_size(wall), Some((20.0, 300.0)));
+    assert_eq!(universe.query_point((510.0, 300.0), &filter), vec![wall]);
+
+    assert!(universe.remove(wall));
+    assert!(universe.query_point((510.0, 60.0), &filter).is_empty());
+}
+
+#[test]
+fn entities_are_looked_up_by_type_and_player() {
+    let mut universe = Universe::new_headless((800.0, 600.0));
+    let player = universe.add_player(7, (100.0, 100.0), (30.0, 30.0), 1.0, 0.2, RED);
+    let first = universe.add_collectible((300.0, 300.0));
+    universe.add_wall((400.0, 50.0), (20.0, 100.0));
+    let second = universe.add_collectible((500.0, 300.0));
+
+    assert_eq!(universe.find_player(7), Some(player));
+    assert_eq!(universe.find_player(8), None);
+    let players: Vec<_> = universe.iter_players().map(|(id, _)| id).collect();
+    assert_eq!(players, vec![player]);
+    assert_eq!(
+        universe.find_by_type(CollidableType::COLLECTABLE),
+        vec![first, second]
+    );
+}
@@ -0,0 +1,162 @@
+// *************************************************************************
+//
+// Copyright (c) 2025 Andrei Gramakov. All rights reserved.
+//
+// This file is licensed under the terms of the MIT license.
+// For a copy, see: https://opensource.org/licenses/MIT
+//
+// site:    https://agramakov.me
+// e-mail:  mail@agramakov.me
+//
+// *************************************************************************
+use cbb_2d::{
+    sys_event::{Event, Subscriber},
+    universe::Universe,
+};
+use std::sync::{Arc, Mutex};
+
+const DELTA_TIME: f32 = 1.0 / 60.0;
+
+/// Event type defined by the game
+#[derive(Debug, Clone, Copy, PartialEq)]
+struct Damage(u32);
+
+#[derive(Debug, Clone, Copy, PartialEq)]
+struct Destroyed;
+
+#[derive(Default)]
+struct QuitCounter {
+    count: u32,
+    is_subscribed: bool,
+}
+
+impl Subscriber for QuitCounter {
+    fn handle_busevent(&mut self, event: &Event) {
+        if matches!(event, Event::Quit) {
+            self.count += 1;
+        }
+    }
+
+    fn is_subscribed(&self) -> bool {
+        self.is_subscribed
+    }
+}
+
+#[test]
+fn handlers_get_only_their_type() {
+    let mut universe = Universe::new_headless((800.0, 600.0));
+    let damage = Arc::new(Mutex::new(Vec::new()));
+    let events = Arc::new(Mutex::new(0));
+    let received = damage.clone();
+    universe.subscribe_to(move |event: &Damage| received.lock().unwrap().push(*event));
+    let counted = events.clone();
+    universe.subscribe_to(move |_: &Event| *counted.lock().unwrap() += 1);
+
+    universe.publish_event(Damage(3));
+    universe.publish_event(Event::Quit);
+    universe.publish_event(Damage(5));
+    universe.step(DELTA_TIME);
+
+    assert_eq!(*damage.lock().unwrap(), vec![Damage(3), Damage(5)]);
+    assert_eq!(*events.lock().unwrap(), 1);
+}
+
+#[test]
+fn unsubscribed_handlers_are_not_called() {
+    let mut universe = Universe::new_headless((800.0, 600.0));
+    let damage = Arc::new(Mutex::new(0));
+    let counted = damage.clone();
+    let id = universe.subscribe_to(move |_: &Damage| *counted.lock().unwrap() += 1);
+
+    universe.publish_event(Damage(1));
+    universe.step(DELTA_TIME);
+    assert!(universe.unsubscribe(id));
+    assert!(!universe.unsubscribe(id));
+    universe.publish_event(Damage(1));
+    universe.step(DELTA_TIME);
+    assert_eq!(*damage.lock().unwrap(), 1);
+}
+
+#[test]
+fn handlers_publish_follow_up_events() {
+    let mut universe = Universe::new_headless((800.0, 600.0));
+    let sender = universe.get_event_sender();
+    let health = Arc::new(Mutex::new(10u32));
+    let destroyed = Arc::new(Mutex::new(0));
+
+    let remaining = health.clone();
+    universe.subscribe_to(move |Damage(amount): &Damage| {
+        let mut health = remaining.lock().unwrap();
+        *health = health.saturating_sub(*amount);
+        if *health == 0 {
+            sender.publish(Destroyed);
+        }
+    });
+    let counted = destroyed.clone();
+    universe.subscribe_to(move |_: &Destroyed| *counted.lock().unwrap() += 1);
+
+    universe.publish_event(Damage(4));
+    universe.step(DELTA_TIME);
+    assert_eq!(*destroyed.lock().unwrap(), 0);
+    universe.publish_event(Damage(6));
+    universe.step(DELTA_TIME);
+    assert_eq!(*health.lock().unwrap(), 0);
+    // The follow-up event waits for the next step
+    assert_eq!(*destroyed.lock().unwrap(), 0);
+    universe.step(DELTA_TIME);
+    assert_eq!(*destroyed.lock().unwrap(), 1);
+}
+
+#[test]
+fn republished_events_are_handled_once_per_step() {
+    let mut universe = Universe::new_headless((800.0, 600.0));
+    let sender = universe.get_event_sender();
+    let damage = Arc::new(Mutex::new(0));
+    let counted = damage.clone();
+    universe.subscribe_to(move |Damage(amount): &Damage| {
+        *counted.lock().unwrap() += amount;
+        sender.publish(Damage(*amount));
+    });
+
+    universe.publish_event(Damage(1));
+    for step in 1..=5 {
+        universe.step(DELTA_TIME);
+        assert_eq!(*damage.lock().unwrap(), step);
+    }
+}
+
+#[test]
+fn subscribers_are_dropped_when_done() {
+    let mut universe = Universe::new_headless((800.0, 600.0));
+    let counter = Arc::new(Mutex::new(QuitCounter {
+        is_subscribed: true,
+        ..Default::default()
+    }));
+    universe.subscribe(counter.clone());
+
+    universe.publish_event(Event::Quit);
+    universe.step(DELTA_TIME);
+    assert_eq!(counter.lock().unwrap().count, 1);
+
+    counter.lock().unwrap().is_subscribed = false;
+    universe.step(DELTA_TIME);
+    universe.publish_event(Event::Quit);
+    universe.step(DELTA_TIME);
+    assert_eq!(counter.lock().unwrap().count, 1);
+}
+
+#[test]
+fn unsubscribed_subscribers_are_not_called() {
+    let mut universe = Universe::new_headless((800.0, 600.0));
+    let counter = Arc::new(Mutex::new(QuitCounter {
+        is_subscribed: true,
+        ..Default::default()
+    }));
+    let id = universe.subscribe(counter.clone());
+    assert!(universe.unsubscribe(id));
+    assert!(!universe.unsubscribe(id));
+
+    universe.publish_event(Event::Quit);
+    universe.step(DELTA_TIME);
+    assert_eq!(counter.lock().unwrap().count, 0);
+}
@@ -0,0 +1,175 @@
+// *************************************************************************
+//
+// Copyright (c) 2025 Andrei Gramakov. All rights reserved.
+//
+// This file is licensed under the terms of the MIT license.
+// For a copy, see: https://opensource.org/licenses/MIT
+//
+// site:    https://agramakov.me
+// e-mail:  mail@agramakov.me
+//
+// *************************************************************************
+use cbb_2d::{
+    recording::MatchRecording,
+    sys_event::Event,
+    sys_input::{InputSource, InputState, ReplayInput, ScriptedInput},
+    universe::Universe,
+};
+use macroquad::{color::RED, input::KeyCode};
+use rand::Rng;
+use std::{env, fs};
+
+const DELTA_TIME: f32 = 1.0 / 60.0;
+
+fn held_key(key: KeyCode, frames: usize) -> ScriptedInput {
+    let mut state = InputState::new();
+    state.press_key(key);
+    let mut script = ScriptedInput::new();
+    script.push_repeated(state, frames);
+    script
+}
+
+fn new_universe() -> Universe {
+    let mut universe = Universe::new_headless((800.0, 600.0));
+    universe.set_random_seed(1);
+    universe.add_player(0, (100.0, 100.0), (30.0, 30.0), 1.0, 0.2, RED);
+    universe
+}
+
+#[test]
+fn scripted_input_moves_the_player() {
+    let mut universe = new_universe();
+    universe.add_input_source(Box::new(held_key(KeyCode::Right, 30)));
+    for _ in 0..60 {
+        universe.step(DELTA_TIME);
+    }
+    let (x, y) = universe.get_player_position(0).unwrap();
+    assert!(x > 150.0, "the player did not move right: {}", x);
+    assert_eq!(y, 100.0);
+}
+
+#[test]
+fn published_events_move_the_player() {
+    let mut universe = new_universe();
+    universe.publish_event(Event::MovePlayer {
+        id: 0,
+        fx: 100.0,
+        fy: 0.0,
+    });
+    for _ in 0..300 {
+        universe.step(DELTA_TIME);
+    }
+    let (x, y) = universe.get_player_position(0).unwrap();
+    assert!(x > 150.0, "the player did not move right: {}", x);
+    assert_eq!(y, 100.0);
+}
+
+#[test]
+fn player_collects_a_collectible_on_the_way() {
+    let mut universe = new_universe();
+    universe.add_collectible((250.0, 110.0));
+    universe.add_input_source(Box::new(held_key(KeyCode::Right, 60)));
+    for _ in 0..120 {
+        universe.step(DELTA_TIME);
+    }
+    assert_eq!(universe.get_player_score(0), 1);
+}
+
+#[test]
+fn same_seed_and_input_give_the_same_match() {
+    let run = || {
+        let mut universe = new_universe();
+        universe.add_random_collectible();
+        universe.add_input_source(Box::new(held_key(KeyCode::Down, 90)));
+        for _ in 0..180 {
+            universe.step(DELTA_TIME);
+        }
+        (universe.get_player_position(0), universe.get_elapsed_time())
+    };
+    assert_eq!(run(), run());
+}
+
+#[test]
+fn same_seed_gives_the_same_random_sequence() {
+    let draw = |seed| {
+        let mut universe = Universe::new_headless((800.0, 600.0));
+        universe.set_random_seed(seed);
+        for _ in 0..5 {
+            universe.add_random_wall();
+            universe.add_random_collectible();
+        }
+        universe.get_rng().gen::<u64>()
+    };
+    assert_eq!(draw(1), draw(1));
+    assert_ne!(draw(1), draw(2));
+}
+
+#[test]
+fn the_timer_ends_the_game() {
+    let mut universe = new_universe();
+    for _ in 0..29 {
+        universe.step(1.0);
+    }
+    assert!(!universe.is_game_over());
+    universe.step(1.0);
+    assert!(universe.is_game_over());
+}
+
+#[test]
+fn movement_does_not_depend_on_the_step_size() {
+    let travel = |delta_time: f32| {
+        let mut universe = new_universe();
+        universe.publish_event(Event::MovePlayer {
+            id: 0,
+            fx: 100.0,
+            fy: 0.0,
+        });
+        for _ in 0..(1.0 / delta_time).round() as usize {
+            universe.step(delta_time);
+        }
+        universe.get_player_position(0).unwrap().0 - 100.0
+    };
+    let (slow, fast) = (travel(1.0 / 60.0), travel(1.0 / 120.0));
+    assert!((slow - fast).abs() < 0.1 * fast, "{} vs {}", slow, fast);
+}
+
+#[test]
+fn recorded_input_replays_the_same_moves() {
+    let zigzag = || {
+        let mut script = held_key(KeyCode::Right, 20);
+        let mut state = InputState::new();
+        state.press_key(KeyCode::Down);
+        script.push_repeated(state, 20);
+        script
+    };
+    let mut universe = new_universe();
+    universe.add_input_source(Box::new(zigzag()));
+    universe.start_recording();
+    for _ in 0..60 {
+        universe.step(DELTA_TIME);
+    }
+    let path = env::temp_dir().join("cbb_2d_headless_replay.txt");
+    universe.stop_recording().unwrap().save(&path).unwrap();
+    let replay = ReplayInput::new(&MatchRecording::load(&path).unwrap());
+    fs::remove_file(&path).unwrap();
+
+    let run = |source: Box<dyn InputSource>| {
+        let mut universe = new_universe();
+        universe.add_input_source(source);
+        for _ in 0..60 {
+            universe.step(DELTA_TIME);
+        }
+        universe.get_player_position(0).unwrap()
+    };
+    let (x, y) = run(Box::new(zigzag()));
+    assert!(x > 100.0 && y > 100.0);
+    assert_eq!(run(Box::new(replay)), (x, y));
+}
+
+#[test]
+fn random_collectible_fits_a_small_screen() {
+    let mut universe = Universe::new_headless((30.0, 30.0));
+    let id = universe.add_random_collectible();
+    let (x, y) = universe.get_position(id).unwrap();
+    assert!((0.0..30.0).contains(&x) && (0.0..30.0).contains(&y));
+}
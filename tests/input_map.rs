@@ -0,0 +1,114 @@
+// *************************************************************************
+//
+// Copyright (c) 2025 Andrei Gramakov. All rights reserved.
+//
+// This file is licensed under the terms of the MIT license.
+// For a copy, see: https://opensource.org/licenses/MIT
+//
+// site:    https://agramakov.me
+// e-mail:  mail@agramakov.me
+//
+// *************************************************************************
+use cbb_2d::{
+    base::FileError,
+    sys_input::{Action, GamepadAxis, InputBinding, InputMap},
+};
+use gamepads::Button;
+use macroquad::input::KeyCode;
+
+const CONFIG: &str = "\
+# Two players, the second one without a gamepad stick
+deadzone 0.25
+player 0 move_up key:Up axis:LeftStickY+
+player 0 no_clip key:RightControl button:ActionDown
+player 1 move_left key:A   # trailing comment
+";
+
+fn parse_error_line(text: &str) -> (usize, String) {
+    match InputMap::parse(text) {
+        Err(FileError::Parse { line, message }) => (line, message),
+        other => panic!("expected a parse error, got {:?}", other),
+    }
+}
+
+#[test]
+fn parses_the_config() {
+    let map = InputMap::parse(CONFIG).unwrap();
+    assert_eq!(map.get_deadzone(), 0.25);
+    assert_eq!(
+        map.get_bindings(0, Action::MoveUp),
+        [
+            InputBinding::Key(KeyCode::Up),
+            InputBinding::GamepadAxis {
+                axis: GamepadAxis::LeftStickY,
+                positive: true
+            }
+        ]
+    );
+    assert_eq!(
+        map.get_bindings(0, Action::NoClip),
+        [
+            InputBinding::Key(KeyCode::RightControl),
+            InputBinding::GamepadButton(Button::ActionDown)
+        ]
+    );
+    assert_eq!(
+        map.get_bindings(1, Action::MoveLeft),
+        [InputBinding::Key(KeyCode::A)]
+    );
+    assert!(map.get_bindings(1, Action::MoveUp).is_empty());
+}
+
+#[test]
+fn serialized_config_parses_back_to_the_same_map() {
+    let mut map = InputMap::new();
+    map.add_default_bindings(0, 0);
+    map.add_default_bindings(1, 1);
+    map.add_default_bindings(7, 2);
+    map.set_deadzone(0.3);
+
+    let text = map.to_config_string();
+    let parsed = InputMap::parse(&text).unwrap();
+    assert_eq!(parsed.to_config_string(), text);
+    assert_eq!(parsed.get_deadzone(), 0.3);
+    for player_id in [0, 1, 7] {
+        for action in Action::ALL {
+            assert_eq!(
+                parsed.get_bindings(player_id, action),
+                map.get_bindings(player_id, action)
+            );
+        }
+    }
+}
+
+#[test]
+fn reports_an_unknown_action() {
+    let (line, message) = parse_error_line("deadzone 0.1\nplayer 0 jump key:Space\n");
+    assert_eq!(line, 2);
+    assert!(message.contains("unknown action 'jump'"), "{}", message);
+}
+
+#[test]
+fn reports_a_bad_deadzone() {
+    let (line, message) = parse_error_line("\n\ndeadzone wide\n");
+    assert_eq!(line, 3);
+    assert!(message.contains("invalid deadzone 'wide'"), "{}", message);
+
+    let (line, message) = parse_error_line("deadzone 1.5\n");
+    assert_eq!(line, 1);
+    assert!(message.contains("invalid deadzone '1.5'"), "{}", message);
+}
+
+#[test]
+fn reports_a_bad_key_name() {
+    let (line, message) = parse_error_line("player 0 move_up key:Up\nplayer 0 move_down key:Dwn\n");
+    assert_eq!(line, 2);
+    assert!(message.contains("unknown key 'Dwn'"), "{}", message);
+}
+
+#[test]
+fn reports_a_binding_without_a_type() {
+    let (line, message) = parse_error_line("player 0 move_up Up\n");
+    assert_eq!(line, 1);
+    assert!(message.contains("no type prefix"), "{}", message);
+}
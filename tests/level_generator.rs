@@ -0,0 +1,131 @@
+// *************************************************************************
+//
+// Copyright (c) 2025 Andrei Gramakov. All rights reserved.
+//
+// This file is licensed under the terms of the MIT license.
+// For a copy, see: https://opensource.org/licenses/MIT
+//
+// site:    https://agramakov.me
+// e-mail:  mail@agramakov.me
+//
+// *************************************************************************
+use cbb_2d::{
+    level::{Level, LevelGenerator},
+    objects::COLLECTIBLE_SIZE,
+};
+use rand::{rngs::StdRng, SeedableRng};
+use std::collections::{HashSet, VecDeque};
+
+const PLAYER_SIZE: (f32, f32) = (30.0, 30.0);
+/// Finer than the generator cells, so the check does not share its grid
+const STEP: f32 = 5.0;
+
+fn overlaps(a: ((f32, f32), (f32, f32)), b: ((f32, f32), (f32, f32))) -> bool {
+    let (((ax, ay), (aw, ah)), ((bx, by), (bw, bh))) = (a, b);
+    ax < bx + bw && ax + aw > bx && ay < by + bh && ay + ah > by
+}
+
+/// Player positions reachable from `spawn` moving in `STEP` increments
+fn reachable_from(level: &Level, spawn: (f32, f32)) -> Vec<(f32, f32)> {
+    let position =
+        |(col, row): (i32, i32)| (spawn.0 + col as f32 * STEP, spawn.1 + row as f32 * STEP);
+    let is_free = |(x, y): (f32, f32)| {
+        x >= 0.0
+            && y >= 0.0
+            && x + PLAYER_SIZE.0 <= level.size_wh.0
+            && y + PLAYER_SIZE.1 <= level.size_wh.1
+            && !level
+                .walls
+                .iter()
+                .any(|wall| overlaps(((x, y), PLAYER_SIZE), *wall))
+    };
+    let mut seen = HashSet::from([(0, 0)]);
+    let mut queue = VecDeque::from([(0, 0)]);
+    let mut reached = Vec::new();
+    while let Some(cell) = queue.pop_front() {
+        reached.push(position(cell));
+        for (dc, dr) in [(1, 0), (-1, 0), (0, 1), (0, -1)] {
+            let next = (cell.0 + dc, cell.1 + dr);
+            if !seen.contains(&next) && is_free(position(next)) {
+                seen.insert(next);
+                queue.push_back(next);
+            }
+        }
+    }
+    reached
+}
+
+fn new_generator() -> LevelGenerator {
+    let mut generator = LevelGenerator::new((800.0, 600.0));
+    generator.set_player_size(PLAYER_SIZE);
+    generator.add_player_spawn((100.0, 100.0));
+    generator.add_player_spawn((670.0, 470.0));
+    generator
+}
+
+#[test]
+fn every_collectible_is_reachable_from_every_spawn() {
+    let generator = new_generator();
+    for seed in 0..5 {
+        let level = generator
+            .generate(&mut StdRng::seed_from_u64(seed))
+            .expect("no level generated");
+        assert!(!level.collectibles.is_empty());
+        for spawn in &level.player_spawns {
+            let reached = reachable_from(&level, *spawn);
+            for collectible in &level.collectibles {
+                let collectible_rect = (*collectible, (COLLECTIBLE_SIZE, COLLECTIBLE_SIZE));
+                assert!(
+                    reached
+                        .iter()
+                        .any(|player| overlaps((*player, PLAYER_SIZE), collectible_rect)),
+                    "seed {}: {:?} is not reachable from {:?}",
+                    seed,
+                    collectible,
+                    spawn
+                );
+            }
+        }
+    }
+}
+
+#[test]
+fn same_seed_gives_the_same_level() {
+    let generator = new_generator();
+    let a = generator.generate(&mut StdRng::seed_from_u64(7)).unwrap();
+    let b = generator.generate(&mut StdRng::seed_from_u64(7)).unwrap();
+    assert_eq!(a.walls, b.walls);
+    assert_eq!(a.collectibles, b.collectibles);
+}
+
+#[test]
+fn walls_bigger_than_the_level_are_cut() {
+    let mut generator = new_generator();
+    generator.set_walls(5, (10.0..2000.0, 10.0..2000.0));
+    if let Some(level) = generator.generate(&mut StdRng::seed_from_u64(1)) {
+        for ((x, y), (w, h)) in level.walls {
+            assert!(x + w <= 800.0 && y + h <= 600.0);
+        }
+    }
+}
+
+#[test]
+fn impossible_walls_give_no_level() {
+    let mut generator = new_generator();
+    generator.set_walls(5, (50.0..50.0, 10.0..20.0));
+    assert!(generator.generate(&mut StdRng::seed_from_u64(1)).is_none());
+    generator.set_walls(5, (900.0..1000.0, 10.0..20.0));
+    assert!(generator.generate(&mut StdRng::seed_from_u64(1)).is_none());
+}
+
+#[test]
+#[should_panic(expected = "invalid cell size")]
+fn rejects_a_zero_cell_size() {
+    new_generator().set_cell_size(0.0);
+}
+
+#[test]
+#[should_panic(expected = "invalid cell size")]
+fn rejects_a_negative_cell_size() {
+    new_generator().set_cell_size(-10.0);
+}
@@ -0,0 +1,114 @@
+// *************************************************************************
+//
+// Copyright (c) 2025 Andrei Gramakov. All rights reserved.
+//
+// This file is licensed under the terms of the MIT license.
+// For a copy, see: https://opensource.org/licenses/MIT
+//
+// site:    https://agramakov.me
+// e-mail:  mail@agramakov.me
+//
+// *************************************************************************
+use cbb_2d::{
+    sys_input::{Action, GamepadAxis, InputBinding, InputState, ScriptedInput},
+    universe::Universe,
+};
+use macroquad::{
+    color::{BLUE, GREEN, RED},
+    input::KeyCode,
+};
+
+const DELTA_TIME: f32 = 1.0 / 60.0;
+
+/// Three players in a column, far enough from each other not to collide
+fn new_universe() -> Universe {
+    let mut universe = Universe::new_headless((800.0, 600.0));
+    universe.add_player(0, (400.0, 100.0), (30.0, 30.0), 1.0, 0.2, RED);
+    universe.add_player(1, (400.0, 300.0), (30.0, 30.0), 1.0, 0.2, BLUE);
+    universe.add_player(2, (400.0, 500.0), (30.0, 30.0), 1.0, 0.2, GREEN);
+    universe
+}
+
+/// Run the frame for a second and return how far every player moved
+fn run_frame(universe: &mut Universe, frame: InputState) -> Vec<(f32, f32)> {
+    let start: Vec<_> = (0..3)
+        .map(|id| universe.get_player_position(id).unwrap())
+        .collect();
+    let mut script = ScriptedInput::new();
+    script.push_repeated(frame, 30);
+    universe.add_input_source(Box::new(script));
+    for _ in 0..60 {
+        universe.step(DELTA_TIME);
+    }
+    (0..3)
+        .map(|id| {
+            let (x, y) = universe.get_player_position(id).unwrap();
+            (x - start[id as usize].0, y - start[id as usize].1)
+        })
+        .collect()
+}
+
+#[test]
+fn keys_move_only_their_player() {
+    let mut universe = new_universe();
+    universe
+        .get_input_map_mut()
+        .bind(2, Action::MoveLeft, InputBinding::Key(KeyCode::J));
+    let mut frame = InputState::new();
+    frame.press_key(KeyCode::Right);
+    frame.press_key(KeyCode::S);
+    frame.press_key(KeyCode::J);
+
+    let moves = run_frame(&mut universe, frame);
+    assert!(moves[0].0 > 10.0 && moves[0].1 == 0.0, "{:?}", moves);
+    assert!(moves[1].0 == 0.0 && moves[1].1 > 10.0, "{:?}", moves);
+    assert!(moves[2].0 < -10.0 && moves[2].1 == 0.0, "{:?}", moves);
+}
+
+#[test]
+fn gamepads_go_to_the_first_player_without_one() {
+    let mut universe = new_universe();
+    universe.assign_gamepad(5, 0);
+    let mut frame = InputState::new();
+    frame
+        .get_gamepad_mut(3)
+        .set_axis(GamepadAxis::LeftStickX, 1.0);
+    frame
+        .get_gamepad_mut(4)
+        .set_axis(GamepadAxis::LeftStickY, 1.0);
+    frame
+        .get_gamepad_mut(5)
+        .set_axis(GamepadAxis::LeftStickX, -1.0);
+
+    // Gamepad 5 is taken by player 0, so 3 and 4 go to players 1 and 2
+    let moves = run_frame(&mut universe, frame);
+    assert!(moves[0].0 < -10.0 && moves[0].1 == 0.0, "{:?}", moves);
+    assert!(moves[1].0 > 10.0 && moves[1].1 == 0.0, "{:?}", moves);
+    assert!(moves[2].0 == 0.0 && moves[2].1 < -10.0, "{:?}", moves);
+}
+
+#[test]
+fn results_are_ordered_by_player_id() {
+    let mut universe = Universe::new_headless((800.0, 600.0));
+    // Player 1 covers the whole screen and collects everything, the others
+    // are out of reach. The players are added out of order on purpose.
+    universe.add_player(2, (1000.0, 1000.0), (30.0, 30.0), 1.0, 0.2, GREEN);
+    universe.add_player(1, (0.0, 0.0), (800.0, 600.0), 1.0, 0.2, BLUE);
+    universe.add_player(0, (1000.0, 2000.0), (30.0, 30.0), 1.0, 0.2, RED);
+    universe.add_random_collectible();
+    for _ in 0..10 {
+        universe.step(DELTA_TIME);
+    }
+
+    let result = universe.get_result();
+    let ids: Vec<u32> = result.scores.keys().copied().collect();
+    assert_eq!(ids, vec![0, 1, 2]);
+    assert_eq!(result.winner, Some(1));
+    assert!(result.get_score(1) > 0);
+    assert_eq!(result.get_score(0), 0);
+    assert_eq!(result.get_score(2), 0);
+    assert_eq!(
+        result.get_scores_text(),
+        format!("P0:0 P1:{} P2:0", result.get_score(1))
+    );
+}
@@ -0,0 +1,188 @@
+// *************************************************************************
+//
+// Copyright (c) 2025 Andrei Gramakov. All rights reserved.
+//
+// This file is licensed under the terms of the MIT license.
+// For a copy, see: https://opensource.org/licenses/MIT
+//
+// site:    https://agramakov.me
+// e-mail:  mail@agramakov.me
+//
+// *************************************************************************
+use cbb_2d::{
+    base::FileError,
+    recording::MatchRecording,
+    sys_event::Event,
+    sys_input::{GamepadAxis, InputState, ScriptedInput},
+    universe::Universe,
+};
+use macroquad::{
+    color::{BLUE, RED},
+    input::KeyCode,
+};
+use std::sync::{Arc, Mutex};
+
+const DELTA_TIME: f32 = 1.0 / 60.0;
+
+/// Frames of every kind: keys, a gamepad, several frames on one tick and
+/// an empty frame
+fn new_recording() -> MatchRecording {
+    let mut recording = MatchRecording::new(1234, 30.0);
+    let mut frame = InputState::new();
+    frame.press_key(KeyCode::Right);
+    frame
+        .get_gamepad_mut(1)
+        .set_axis(GamepadAxis::LeftStickX, -0.5);
+    recording.push_input(0, frame);
+    let mut frame = InputState::new();
+    frame.press_key(KeyCode::Up);
+    recording.push_input(0, frame);
+    recording.push_input(3, InputState::new());
+    recording
+}
+
+fn assert_same(a: &MatchRecording, b: &MatchRecording) {
+    assert_eq!(a.seed, b.seed);
+    assert_eq!(a.tick_rate, b.tick_rate);
+    assert_eq!(a.inputs, b.inputs);
+}
+
+/// Two players, a wall between them and the right border and random
+/// collectibles. The replayed match has to be built the same way as the
+/// recorded one, the replay restores the random seed.
+fn new_match(replay: Option<MatchRecording>) -> Universe {
+    let mut universe = Universe::new_headless((800.0, 600.0));
+    match replay {
+        Some(replay) => universe.start_replay(replay),
+        None => universe.set_random_seed(3),
+    }
+    universe.add_player(0, (100.0, 100.0), (30.0, 30.0), 1.0, 0.2, RED);
+    universe.add_player(1, (100.0, 300.0), (20.0, 20.0), 2.0, 0.05, BLUE);
+    universe.add_wall((300.0, 50.0), (20.0, 400.0));
+    for _ in 0..10 {
+        universe.add_random_collectible();
+    }
+    universe
+}
+
+/// Both players run into the wall, then the first one goes down
+fn new_script() -> ScriptedInput {
+    let mut script = ScriptedInput::new();
+    let mut state = InputState::new();
+    state.press_key(KeyCode::Right);
+    state.press_key(KeyCode::D);
+    script.push_repeated(state, 90);
+    let mut state = InputState::new();
+    state.press_key(KeyCode::Down);
+    state.press_key(KeyCode::RightControl);
+    script.push_repeated(state, 30);
+    script
+}
+
+/// Position and score of every player
+fn snapshot(universe: &Universe) -> Vec<(Option<(f32, f32)>, u32)> {
+    universe
+        .get_player_ids()
+        .into_iter()
+        .map(|id| {
+            (
+                universe.get_player_position(id),
+                universe.get_player_score(id),
+            )
+        })
+        .collect()
+}
+
+#[test]
+fn inputs_survive_the_text_form() {
+    let recording = new_recording();
+    let text = recording.to_record_string();
+    let parsed = MatchRecording::parse(&text).unwrap();
+    assert_same(&recording, &parsed);
+    assert_eq!(parsed.to_record_string(), text);
+    assert_eq!(parsed.get_tick_inputs(0).count(), 2);
+    assert_eq!(parsed.get_tick_inputs(1).count(), 0);
+}
+
+#[test]
+fn saved_recording_loads_back() {
+    let path = std::env::temp_dir().join(format!("cbb_2d_recording_{}.txt", std::process::id()));
+    let recording = new_recording();
+    recording.save(&path).unwrap();
+    let loaded = MatchRecording::load(&path);
+    std::fs::remove_file(&path).unwrap();
+    assert_same(&recording, &loaded.unwrap());
+}
+
+#[test]
+fn reports_the_broken_line() {
+    let text = "seed 1\ntick_rate 60\ninput 0 key:Up\ninput 1 key:Fast\n";
+    match MatchRecording::parse(text) {
+        Err(FileError::Parse { line, message }) => {
+            assert_eq!(line, 4);
+            assert!(message.contains("'Fast'"), "{}", message);
+        }
+        other => panic!("expected a parse error, got {:?}", other),
+    }
+}
+
+#[test]
+fn rejects_a_recording_without_tick_rate() {
+    assert!(matches!(
+        MatchRecording::parse("seed 1\n"),
+        Err(FileError::Invalid(_))
+    ));
+}
+
+#[test]
+fn replay_repeats_the_match_on_every_tick() {
+    let mut universe = new_match(None);
+    universe.add_input_source(Box::new(new_script()));
+    universe.start_recording();
+    let mut recorded = Vec::new();
+    for _ in 0..150 {
+        universe.step(DELTA_TIME);
+        recorded.push(snapshot(&universe));
+    }
+    let recording = universe.stop_recording().unwrap();
+
+    let mut replay = new_match(Some(recording));
+    for (tick, expected) in recorded.iter().enumerate() {
+        replay.step(DELTA_TIME);
+        assert_eq!(&snapshot(&replay), expected, "tick {}", tick);
+    }
+    // The players really moved and scored, the replay did not just stand still
+    assert_ne!(recorded[0], recorded[149]);
+    assert!(recorded[149].iter().any(|(_, score)| *score > 0));
+}
+
+#[test]
+fn game_events_are_published_once_in_the_replay() {
+    // Only the input is recorded, the game publishes its events again
+    let run = |replay: Option<MatchRecording>| {
+        let is_replay = replay.is_some();
+        let mut universe = new_match(replay);
+        if !is_replay {
+            universe.add_input_source(Box::new(new_script()));
+            universe.start_recording();
+        }
+        let color_changes = Arc::new(Mutex::new(0));
+        let counted = color_changes.clone();
+        universe.subscribe_to(move |event: &Event| {
+            if matches!(event, Event::ChangeColor { .. }) {
+                *counted.lock().unwrap() += 1;
+            }
+        });
+        for tick in 0..30 {
+            if tick == 10 {
+                universe.publish_event(Event::ChangeColor { id: 0, color: None });
+            }
+            universe.step(DELTA_TIME);
+        }
+        let color_changes = *color_changes.lock().unwrap();
+        (universe.stop_recording(), color_changes)
+    };
+    let (recording, recorded_changes) = run(None);
+    let (_, replayed_changes) = run(recording);
+    assert_eq!((recorded_changes, replayed_changes), (1, 1));
+}
@@ -0,0 +1,96 @@
+// *************************************************************************
+//
+// Copyright (c) 2025 Andrei Gramakov. All rights reserved.
+//
+// This file is licensed under the terms of the MIT license.
+// For a copy, see: https://opensource.org/licenses/MIT
+//
+// site:    https://agramakov.me
+// e-mail:  mail@agramakov.me
+//
+// *************************************************************************
+use cbb_2d::{
+    rules::{FirstTo, Survival, TimeAttack},
+    sys_input::{InputState, ScriptedInput},
+    universe::Universe,
+};
+use macroquad::{
+    color::{BLUE, RED},
+    input::KeyCode,
+};
+
+const DELTA_TIME: f32 = 1.0 / 60.0;
+
+fn new_survival(time_limit: f32) -> Universe {
+    let mut universe = Universe::new_headless((800.0, 600.0));
+    universe.set_rules(Box::new(Survival::new(time_limit)));
+    universe.add_player(0, (100.0, 100.0), (30.0, 30.0), 1.0, 0.2, RED);
+    universe.add_player(1, (100.0, 400.0), (30.0, 30.0), 1.0, 0.2, BLUE);
+    universe
+}
+
+fn step_until_game_over(universe: &mut Universe, max_steps: usize) {
+    for _ in 0..max_steps {
+        if universe.is_game_over() {
+            return;
+        }
+        universe.step(DELTA_TIME);
+    }
+}
+
+#[test]
+fn time_attack_ends_when_the_time_is_up() {
+    let mut universe = Universe::new_headless((800.0, 600.0));
+    universe.set_rules(Box::new(TimeAttack::new(1)));
+    universe.add_player(0, (100.0, 100.0), (30.0, 30.0), 1.0, 0.2, RED);
+    step_until_game_over(&mut universe, 600);
+
+    assert!(universe.is_game_over());
+    assert!((1.0..1.1).contains(&universe.get_elapsed_time()));
+}
+
+#[test]
+fn first_to_ends_when_a_player_reaches_the_target() {
+    let mut universe = Universe::new_headless((800.0, 600.0));
+    universe.set_rules(Box::new(FirstTo::new(3)));
+    // Covers the whole screen, so every collectible spawned is collected
+    universe.add_player(0, (0.0, 0.0), (800.0, 600.0), 1.0, 0.2, RED);
+    universe.add_player(1, (1000.0, 1000.0), (30.0, 30.0), 1.0, 0.2, BLUE);
+    universe.add_random_collectible();
+    step_until_game_over(&mut universe, 600);
+
+    let result = universe.get_result();
+    assert!(universe.is_game_over());
+    assert_eq!(result.winner, Some(0));
+    assert_eq!(result.get_score(0), 3);
+    assert_eq!(result.get_score(1), 0);
+}
+
+#[test]
+fn the_player_who_scores_survives() {
+    let mut universe = new_survival(1.0);
+    universe.add_collectible((200.0, 110.0));
+    let mut state = InputState::new();
+    state.press_key(KeyCode::Right);
+    let mut script = ScriptedInput::new();
+    script.push_repeated(state, 30);
+    universe.add_input_source(Box::new(script));
+
+    step_until_game_over(&mut universe, 600);
+
+    let result = universe.get_result();
+    assert!(universe.is_game_over());
+    assert_eq!(result.winner, Some(0));
+    assert_eq!(result.get_score(0), 1);
+    assert_eq!(result.get_score(1), 0);
+}
+
+#[test]
+fn players_starving_together_draw() {
+    let mut universe = new_survival(0.5);
+    step_until_game_over(&mut universe, 600);
+
+    assert!(universe.is_game_over());
+    assert!(universe.get_elapsed_time() < 1.0);
+    assert_eq!(universe.get_result().winner, None);
+}
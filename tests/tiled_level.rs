@@ -0,0 +1,97 @@
+// *************************************************************************
+//
+// Copyright (c) 2025 Andrei Gramakov. All rights reserved.
+//
+// This file is licensed under the terms of the MIT license.
+// For a copy, see: https://opensource.org/licenses/MIT
+//
+// site:    https://agramakov.me
+// e-mail:  mail@agramakov.me
+//
+// *************************************************************************
+use cbb_2d::level::{Level, TiledBackground};
+
+const MAP: &str = r#"{
+    "width": 4, "height": 3, "tilewidth": 16, "tileheight": 16, "infinite": false,
+    "layers": [
+        {"name": "ground", "type": "tilelayer", "width": 4, "height": 3, "opacity": 1,
+         "data": [2, 2, 2, 2, 2, 2, 2, 2, 2, 2, 2, 2]},
+        {"name": "collision", "type": "tilelayer", "width": 4, "height": 3, "opacity": 1,
+         "data": [1, 1, 0, 1, 0, 0, 0, 0, 1, 2147483649, 1, 1]},
+        {"name": "things", "type": "group", "layers": [
+            {"name": "objects", "type": "objectgroup", "objects": [
+                {"name": "1", "type": "player_spawn", "x": 10, "y": 11, "width": 0, "height": 0},
+                {"name": "", "class": "player_spawn", "x": 20, "y": 21, "width": 0, "height": 0},
+                {"name": "0", "class": "player_spawn", "x": 30, "y": 31, "width": 0, "height": 0},
+                {"name": "x", "type": "player_spawn", "x": 40, "y": 41, "width": 0, "height": 0},
+                {"name": "", "class": "wall", "x": 5, "y": 6, "width": 7, "height": 8},
+                {"name": "", "class": "collectible", "x": 50, "y": 12, "width": 0, "height": 0},
+                {"name": "", "class": "collectible_spawner", "x": 100, "y": 200,
+                 "width": 50, "height": 30},
+                {"name": "", "class": "collectible_spawner", "x": 7, "y": 9,
+                 "width": 0, "height": 0}
+            ]}
+        ]}
+    ]
+}"#;
+
+#[test]
+fn collision_tiles_are_merged_by_row() {
+    let level = Level::from_tiled_json(MAP).unwrap();
+    assert_eq!(level.size_wh, (64.0, 48.0));
+    assert_eq!(
+        level.walls,
+        vec![
+            ((0.0, 0.0), (32.0, 16.0)),
+            ((48.0, 0.0), (16.0, 16.0)),
+            // The flip flags do not make a tile empty
+            ((0.0, 32.0), (64.0, 16.0)),
+            ((5.0, 6.0), (7.0, 8.0)),
+        ]
+    );
+}
+
+#[test]
+fn spawns_are_ordered_by_player_id() {
+    let level = Level::from_tiled_json(MAP).unwrap();
+    assert_eq!(
+        level.player_spawns,
+        vec![(30.0, 31.0), (10.0, 11.0), (20.0, 21.0), (40.0, 41.0)]
+    );
+    assert_eq!(level.collectibles, vec![(50.0, 12.0)]);
+}
+
+#[test]
+fn spawner_areas_become_a_grid_of_points() {
+    let level = Level::from_tiled_json(MAP).unwrap();
+    assert_eq!(
+        level.collectible_spawn_points,
+        vec![
+            (100.0, 200.0),
+            (120.0, 200.0),
+            (140.0, 200.0),
+            (100.0, 220.0),
+            (120.0, 220.0),
+            (140.0, 220.0),
+            // A point spawner is a single point
+            (7.0, 9.0),
+        ]
+    );
+}
+
+#[test]
+fn infinite_maps_are_rejected() {
+    assert!(Level::from_tiled_json(r#"{"infinite": true, "layers": []}"#).is_err());
+}
+
+#[test]
+fn background_skips_object_and_group_layers() {
+    assert!(TiledBackground::new(MAP, &[]).is_ok());
+}
+
+#[test]
+fn background_rejects_external_tilesets() {
+    let map = r#"{"width": 1, "height": 1, "tilewidth": 16, "tileheight": 16,
+        "layers": [], "tilesets": [{"firstgid": 1, "source": "tiles.tsj"}]}"#;
+    assert!(TiledBackground::new(map, &[]).is_err());
+}
@@ -0,0 +1,67 @@
+// *************************************************************************
+//
+// Copyright (c) 2025 Andrei Gramakov. All rights reserved.
+//
+// This file is licensed under the terms of the MIT license.
+// For a copy, see: https://opensource.org/licenses/MIT
+//
+// site:    https://agramakov.me
+// e-mail:  mail@agramakov.me
+//
+// *************************************************************************
+use cbb_2d::timestep::FixedTimestep;
+
+#[test]
+fn accumulates_the_frame_time_into_ticks() {
+    let mut timestep = FixedTimestep::new(10.0, 5);
+    assert_eq!(timestep.advance(0.05), 0);
+    assert_eq!(timestep.advance(0.06), 1);
+    assert_eq!(timestep.advance(0.2), 2);
+}
+
+#[test]
+fn drops_the_time_beyond_the_catch_up_cap() {
+    let mut timestep = FixedTimestep::new(10.0, 3);
+    assert_eq!(timestep.advance(1.05), 3);
+    assert!(timestep.get_alpha() < 1.0);
+    assert_eq!(timestep.advance(0.0), 0);
+}
+
+#[test]
+fn alpha_is_the_passed_fraction_of_the_next_tick() {
+    let mut timestep = FixedTimestep::new(10.0, 5);
+    timestep.advance(0.125);
+    assert!((timestep.get_alpha() - 0.25).abs() < 1e-4);
+}
+
+#[test]
+fn changes_the_tick_rate() {
+    let mut timestep = FixedTimestep::default();
+    timestep.set_tick_rate(30.0);
+    assert!((timestep.get_tick_rate() - 30.0).abs() < 1e-3);
+    assert_eq!(timestep.advance(0.11), 3);
+}
+
+#[test]
+#[should_panic(expected = "invalid tick rate")]
+fn rejects_a_zero_tick_rate() {
+    FixedTimestep::default().set_tick_rate(0.0);
+}
+
+#[test]
+#[should_panic(expected = "invalid tick rate")]
+fn rejects_a_negative_tick_rate() {
+    FixedTimestep::new(-60.0, 5);
+}
+
+#[test]
+#[should_panic(expected = "no catch up steps")]
+fn rejects_zero_catch_up_steps() {
+    FixedTimestep::default().set_max_catch_up_steps(0);
+}
+
+#[test]
+#[should_panic(expected = "no catch up steps")]
+fn rejects_zero_catch_up_steps_on_creation() {
+    FixedTimestep::new(60.0, 0);
+}